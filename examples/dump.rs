@@ -0,0 +1,34 @@
+use clap::{Arg, ArgAction, Command};
+use wsdl::{owned, FileResolver, WsdlSet, WsdlSources};
+
+fn main() -> anyhow::Result<()> {
+    let matches = Command::new("WSDL dump example")
+        .args(&[
+            Arg::new("input")
+                .num_args(1)
+                .required(true)
+                .help("Input wsdl file"),
+            Arg::new("json")
+                .long("json")
+                .action(ArgAction::SetTrue)
+                .help("Emit the model as JSON"),
+        ])
+        .get_matches();
+
+    let input = std::path::absolute(matches.get_one::<String>("input").unwrap())?;
+
+    let sources = WsdlSources::load(FileResolver::new(), &input.to_string_lossy())?;
+    let set = WsdlSet::parse(&sources)?;
+    if set.root().is_none() {
+        anyhow::bail!("{} is not a WSDL 1.1 document", input.display());
+    }
+
+    let definitions = owned::Definitions::from_set(&set)?;
+    if matches.get_flag("json") {
+        println!("{}", serde_json::to_string_pretty(&definitions)?);
+    } else {
+        println!("{:#?}", definitions);
+    }
+
+    Ok(())
+}
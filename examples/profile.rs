@@ -0,0 +1,79 @@
+use std::path::{Path, PathBuf};
+
+use clap::{Arg, Command};
+use wsdl::{FileResolver, WsdlSet, WsdlSources};
+
+fn main() -> anyhow::Result<()> {
+    let matches = Command::new("WS-I Basic Profile checker example")
+        .args(&[Arg::new("input")
+            .num_args(1..)
+            .default_value("assets")
+            .help("Input wsdl files, or directories to search for them")])
+        .get_matches();
+
+    let mut files = Vec::new();
+    for input in matches.get_many::<String>("input").unwrap() {
+        collect(Path::new(input), &mut files)?;
+    }
+
+    let mut failed = 0;
+    for file in files {
+        let file = std::path::absolute(file)?;
+        let sources = WsdlSources::load(FileResolver::new(), &file.to_string_lossy())?;
+        let set = WsdlSet::parse(&sources)?;
+
+        // Skip schemas and other XML that happen to be lying around.
+        let Some(definitions) = set.root() else {
+            continue;
+        };
+
+        let diagnostics = definitions.check_basic_profile();
+        if diagnostics.is_empty() {
+            println!("{}: conforms", file.display());
+            continue;
+        }
+
+        if diagnostics.iter().any(|d| d.is_error()) {
+            failed += 1;
+        }
+
+        for diagnostic in diagnostics {
+            match set.locate(&diagnostic.error) {
+                Some(location) => println!("{}: {}", location, diagnostic),
+                None => println!("{}: {}", file.display(), diagnostic),
+            }
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!("{} documents do not conform", failed);
+    }
+
+    Ok(())
+}
+
+// Gather the WSDL files under a path, in a stable order.
+fn collect(path: &Path, files: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+    if !path.is_dir() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+
+    let mut entries = std::fs::read_dir(path)?
+        .map(|e| e.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+
+    for entry in entries {
+        let wsdl = matches!(
+            entry.extension().and_then(|e| e.to_str()),
+            Some("wsdl" | "xml")
+        );
+
+        if entry.is_dir() || wsdl {
+            collect(&entry, files)?;
+        }
+    }
+
+    Ok(())
+}
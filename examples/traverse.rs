@@ -1,5 +1,5 @@
 use clap::{Arg, Command};
-use wsdl::WsDefinitions;
+use wsdl::{WsDefinitions, WsError};
 
 fn main() -> anyhow::Result<()> {
     let matches = Command::new("WSDL tree traversal example")
@@ -9,16 +9,36 @@ fn main() -> anyhow::Result<()> {
             .help("Input wsdl file")])
         .get_matches();
 
-    let input = matches.get_one::<String>("input").unwrap();
+    let path = matches.get_one::<String>("input").unwrap();
 
-    let input = std::fs::read_to_string(&input)?;
+    let input = std::fs::read_to_string(path)?;
     let document = roxmltree::Document::parse(&input)?;
 
-    let wsdef = WsDefinitions::from_document(&document)?;
+    // Point at the offending XML if the WSDL turns out to be malformed.
+    traverse(&document).map_err(|e| match e.downcast_ref::<WsError>() {
+        Some(error) => anyhow::anyhow!("{}: {}", error.locate(path), error),
+        None => e,
+    })
+}
+
+fn traverse(document: &roxmltree::Document) -> anyhow::Result<()> {
+    let wsdef = WsDefinitions::from_document(document)?;
     for service in wsdef.services()? {
         println!("Service: {}", service.name()?);
     }
 
+    for endpoint in wsdef.endpoints()? {
+        println!(
+            "Endpoint: {}/{} -> {}",
+            endpoint.service.name()?,
+            endpoint.port.name()?,
+            match &endpoint.address {
+                Some(address) => address.location()?,
+                None => "(unknown)",
+            }
+        );
+    }
+
     for binding in wsdef.bindings()? {
         println!(
             "Binding: {} -> {}",
@@ -42,7 +62,7 @@ fn main() -> anyhow::Result<()> {
                         let tn = if let Some(ns) = tn.namespace() {
                             format!("`{}:{}`", ns, tn.name())
                         } else {
-                            format!("{}", tn.name())
+                            tn.name().to_string()
                         };
 
                         s.push_str(&format!("{}: {}", part.name()?, tn));
@@ -62,12 +82,16 @@ fn main() -> anyhow::Result<()> {
                     if let Some(ns) = tn.namespace() {
                         format!("`{}:{}`", ns, tn.name())
                     } else {
-                        format!("{}", tn.name())
+                        tn.name().to_string()
                     }
                 } else {
                     "()".to_string()
                 }
             );
+
+            for fault in operation.faults()? {
+                println!("    throws {}: {}", fault.name()?, fault.message()?.name()?);
+            }
         }
     }
 
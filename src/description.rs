@@ -0,0 +1,281 @@
+use roxmltree::{Document, Node};
+
+use crate::wsdl::{missing_root, Result};
+use crate::{MessageExchangePattern, Ws2Description, WsDefinitions, WsProtocol};
+
+/// The version of the WSDL specification a document follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WsdlVersion {
+    /// WSDL 1.1, rooted at a `definitions` element.
+    Wsdl11,
+    /// WSDL 2.0, rooted at a `description` element.
+    Wsdl20,
+}
+
+/// An operation of a [WsDescription], regardless of WSDL version.
+#[derive(Debug, Clone)]
+pub struct WsOperationInfo<'a, 'input> {
+    /// The name of the port type or interface declaring the operation.
+    pub interface: &'a str,
+    pub name: &'a str,
+    pub pattern: MessageExchangePattern,
+    pub node: Node<'a, 'input>,
+}
+
+/// An endpoint of a [WsDescription], regardless of WSDL version.
+#[derive(Debug, Clone)]
+pub struct WsEndpointInfo<'a, 'input> {
+    /// The name of the service offering the endpoint.
+    pub service: &'a str,
+    /// The name of the port or endpoint.
+    pub name: &'a str,
+    /// The name of the binding the endpoint is reached with.
+    pub binding: &'a str,
+    pub address: Option<&'a str>,
+    pub protocol: Option<WsProtocol>,
+    pub node: Node<'a, 'input>,
+}
+
+/// A service of a [WsDescription], regardless of WSDL version.
+#[derive(Debug, Clone)]
+pub struct WsServiceInfo<'a, 'input> {
+    pub name: &'a str,
+    pub endpoints: Vec<WsEndpointInfo<'a, 'input>>,
+    pub node: Node<'a, 'input>,
+}
+
+/// A WSDL document of either version. This offers a common view of the
+/// services, operations and endpoints of a document, for callers that do
+/// not care which version they were handed. Match on the variants to get
+/// to the version-specific wrappers.
+#[derive(Debug, Clone)]
+pub enum WsDescription<'a, 'input> {
+    Wsdl11(WsDefinitions<'a, 'input>),
+    Wsdl20(Ws2Description<'a, 'input>),
+}
+
+impl<'a, 'input> WsDescription<'a, 'input> {
+    /// Wrap a `definitions` or `description` element, depending on its namespace.
+    pub fn from_node(node: Node<'a, 'input>) -> Result<Self> {
+        if let Ok(definitions) = WsDefinitions::from_node(node) {
+            return Ok(Self::Wsdl11(definitions));
+        }
+
+        if let Ok(description) = Ws2Description::from_node(node) {
+            return Ok(Self::Wsdl20(description));
+        }
+
+        Err(missing_root(node, "definitions"))
+    }
+
+    /// Detect the WSDL version of a document from its root element.
+    pub fn from_document(document: &'a Document<'input>) -> Result<Self> {
+        Self::from_node(document.root_element())
+    }
+
+    pub fn version(&self) -> WsdlVersion {
+        match self {
+            Self::Wsdl11(_) => WsdlVersion::Wsdl11,
+            Self::Wsdl20(_) => WsdlVersion::Wsdl20,
+        }
+    }
+
+    pub fn target_namespace(&self) -> Option<&'a str> {
+        match self {
+            Self::Wsdl11(d) => d.target_namespace(),
+            Self::Wsdl20(d) => d.target_namespace().ok(),
+        }
+    }
+
+    /// List the operations of every port type or interface, excluding
+    /// operations an interface inherits.
+    pub fn operations(&self) -> Result<Vec<WsOperationInfo<'a, 'input>>> {
+        let mut operations = Vec::new();
+
+        match self {
+            Self::Wsdl11(d) => {
+                for port_type in d.port_types()? {
+                    for operation in port_type.operations()? {
+                        operations.push(WsOperationInfo {
+                            interface: port_type.name()?,
+                            name: operation.name()?,
+                            pattern: operation.kind()?.into(),
+                            node: operation.node(),
+                        });
+                    }
+                }
+            }
+            Self::Wsdl20(d) => {
+                for interface in d.interfaces() {
+                    for operation in interface.operations() {
+                        operations.push(WsOperationInfo {
+                            interface: interface.name()?,
+                            name: operation.name()?,
+                            pattern: operation.pattern()?,
+                            node: operation.node(),
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(operations)
+    }
+
+    /// List every service, along with its endpoints.
+    pub fn services(&self) -> Result<Vec<WsServiceInfo<'a, 'input>>> {
+        let mut services = Vec::new();
+
+        match self {
+            Self::Wsdl11(d) => {
+                for service in d.services()? {
+                    let mut endpoints = Vec::new();
+                    for port in service.ports()? {
+                        let address = port.address()?;
+
+                        endpoints.push(WsEndpointInfo {
+                            service: service.name()?,
+                            name: port.name()?,
+                            binding: port.binding()?.name()?,
+                            address: address.as_ref().map(|a| a.location()).transpose()?,
+                            protocol: address.as_ref().map(|a| a.protocol()),
+                            node: port.node(),
+                        });
+                    }
+
+                    services.push(WsServiceInfo {
+                        name: service.name()?,
+                        endpoints,
+                        node: service.node(),
+                    });
+                }
+            }
+            Self::Wsdl20(d) => {
+                for service in d.services() {
+                    let mut endpoints = Vec::new();
+                    for endpoint in service.endpoints() {
+                        let binding = endpoint.binding()?;
+
+                        endpoints.push(WsEndpointInfo {
+                            service: service.name()?,
+                            name: endpoint.name()?,
+                            binding: binding.name()?,
+                            address: endpoint.address(),
+                            protocol: binding.protocol()?,
+                            node: endpoint.node(),
+                        });
+                    }
+
+                    services.push(WsServiceInfo {
+                        name: service.name()?,
+                        endpoints,
+                        node: service.node(),
+                    });
+                }
+            }
+        }
+
+        Ok(services)
+    }
+
+    /// List every endpoint of every service.
+    pub fn endpoints(&self) -> Result<Vec<WsEndpointInfo<'a, 'input>>> {
+        Ok(self
+            .services()?
+            .into_iter()
+            .flat_map(|s| s.endpoints)
+            .collect())
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        match self {
+            Self::Wsdl11(d) => d.documentation(),
+            Self::Wsdl20(d) => d.documentation(),
+        }
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        match self {
+            Self::Wsdl11(d) => d.node(),
+            Self::Wsdl20(d) => d.node(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describe_wsdl_20() {
+        let text = include_str!("../assets/greath/reservation.wsdl");
+        let document = Document::parse(text).unwrap();
+        let description = WsDescription::from_document(&document).unwrap();
+        assert_eq!(description.version(), WsdlVersion::Wsdl20);
+        assert_eq!(
+            description.target_namespace(),
+            Some("http://greath.example.com/2004/wsdl/resSvc")
+        );
+        assert!(description.documentation().is_some());
+        assert_eq!(description.node(), document.root_element());
+
+        let operations = description.operations().unwrap();
+        assert_eq!(operations.len(), 1);
+        assert_eq!(operations[0].interface, "reservationInterface");
+        assert_eq!(operations[0].name, "opCheckAvailability");
+        assert_eq!(operations[0].pattern, MessageExchangePattern::InOut);
+
+        let services = description.services().unwrap();
+        assert_eq!(services.len(), 1);
+        assert_eq!(services[0].name, "reservationService");
+        let endpoints = description.endpoints().unwrap();
+        assert_eq!(endpoints.len(), 1);
+        assert_eq!(endpoints[0].service, "reservationService");
+        assert_eq!(endpoints[0].name, "reservationEndpoint");
+        assert_eq!(endpoints[0].binding, "reservationSOAPBinding");
+        assert_eq!(
+            endpoints[0].address,
+            Some("http://greath.example.com/2004/reservation")
+        );
+        assert_eq!(endpoints[0].protocol, Some(WsProtocol::Soap12));
+    }
+
+    #[test]
+    fn describe_wsdl_11() {
+        let text = include_str!("../assets/weather/weather.wsdl");
+        let document = Document::parse(text).unwrap();
+        let description = WsDescription::from_document(&document).unwrap();
+        assert_eq!(description.version(), WsdlVersion::Wsdl11);
+        assert!(matches!(description, WsDescription::Wsdl11(_)));
+
+        let operations = description.operations().unwrap();
+        assert!(!operations.is_empty());
+        assert!(operations
+            .iter()
+            .all(|o| o.pattern == MessageExchangePattern::InOut));
+        assert_eq!(operations[0].interface, "WeatherSoap");
+        assert_eq!(operations[0].name, "GetWeatherInformation");
+
+        let endpoints = description.endpoints().unwrap();
+        let summary: Vec<_> = endpoints.iter().map(|e| (e.name, e.protocol)).collect();
+        assert_eq!(
+            summary,
+            [
+                ("WeatherSoap", Some(WsProtocol::Soap11)),
+                ("WeatherSoap12", Some(WsProtocol::Soap12)),
+                ("WeatherHttpGet", Some(WsProtocol::Http)),
+                ("WeatherHttpPost", Some(WsProtocol::Http)),
+            ]
+        );
+        assert!(endpoints.iter().all(|e| e.service == "Weather"));
+    }
+
+    #[test]
+    fn reject_other_documents() {
+        let document = Document::parse(r#"<schema xmlns="urn:other"/>"#).unwrap();
+        assert!(WsDescription::from_document(&document).is_err());
+    }
+}
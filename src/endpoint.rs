@@ -0,0 +1,190 @@
+use roxmltree::Node;
+
+use crate::wsdl::Result;
+use crate::{
+    HttpAddress, SoapAddress, SoapVersion, WsBinding, WsDefinitions, WsService, WsServicePort,
+};
+
+/// The protocol a [WsServicePort] is reached over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum WsProtocol {
+    Soap11,
+    Soap12,
+    Http,
+}
+
+/// The network address of a [WsServicePort].
+#[derive(Debug, Clone)]
+pub enum WsAddress<'a, 'input> {
+    /// A `soap:address` or `soap12:address`.
+    Soap(SoapAddress<'a, 'input>),
+    /// An `http:address`.
+    Http(HttpAddress<'a, 'input>),
+}
+
+impl<'a, 'input> WsAddress<'a, 'input> {
+    /// Retrieve the URL of the endpoint.
+    pub fn location(&self) -> Result<&'a str> {
+        match self {
+            Self::Soap(a) => a.location(),
+            Self::Http(a) => a.location(),
+        }
+    }
+
+    /// Retrieve the protocol the endpoint is reached over.
+    pub fn protocol(&self) -> WsProtocol {
+        match self {
+            Self::Soap(a) => match a.version() {
+                SoapVersion::Soap11 => WsProtocol::Soap11,
+                SoapVersion::Soap12 => WsProtocol::Soap12,
+            },
+            Self::Http(_) => WsProtocol::Http,
+        }
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        match self {
+            Self::Soap(a) => a.node(),
+            Self::Http(a) => a.node(),
+        }
+    }
+}
+
+/// A single port of a service, along with everything needed to reach it.
+/// See [WsDefinitions::endpoints].
+#[derive(Debug, Clone)]
+pub struct WsEndpoint<'a, 'input> {
+    pub service: WsService<'a, 'input>,
+    pub port: WsServicePort<'a, 'input>,
+    pub binding: WsBinding<'a, 'input>,
+    /// The address of the port, unless it uses an address extension this
+    /// library does not recognize.
+    pub address: Option<WsAddress<'a, 'input>>,
+    pub protocol: Option<WsProtocol>,
+}
+
+impl<'a, 'input> WsServicePort<'a, 'input> {
+    /// Retrieve the address of this port, if it has a SOAP or HTTP address.
+    pub fn address(&self) -> Result<Option<WsAddress<'a, 'input>>> {
+        if let Some(address) = self.soap_address()? {
+            return Ok(Some(WsAddress::Soap(address)));
+        }
+
+        Ok(self.http_address()?.map(WsAddress::Http))
+    }
+}
+
+impl<'a, 'input> WsDefinitions<'a, 'input> {
+    /// List every port of every service in these definitions, along with its
+    /// binding and address.
+    pub fn endpoints(&self) -> Result<Vec<WsEndpoint<'a, 'input>>> {
+        let mut endpoints = Vec::new();
+
+        for service in self.services()? {
+            for port in service.ports()? {
+                let address = port.address()?;
+
+                endpoints.push(WsEndpoint {
+                    service: service.clone(),
+                    binding: port.binding()?,
+                    protocol: address.as_ref().map(|a| a.protocol()),
+                    address,
+                    port,
+                });
+            }
+        }
+
+        Ok(endpoints)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use roxmltree::Document;
+
+    #[test]
+    fn list_endpoints() {
+        let text = include_str!("../assets/weather/weather.wsdl");
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+
+        let endpoints = definitions.endpoints().unwrap();
+        let summary: Vec<_> = endpoints
+            .iter()
+            .map(|e| {
+                (
+                    e.port.name().unwrap(),
+                    e.binding.name().unwrap(),
+                    e.protocol,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("WeatherSoap", "WeatherSoap", Some(WsProtocol::Soap11)),
+                ("WeatherSoap12", "WeatherSoap12", Some(WsProtocol::Soap12)),
+                ("WeatherHttpGet", "WeatherHttpGet", Some(WsProtocol::Http)),
+                ("WeatherHttpPost", "WeatherHttpPost", Some(WsProtocol::Http)),
+            ]
+        );
+
+        for endpoint in &endpoints {
+            assert_eq!(endpoint.service.name().unwrap(), "Weather");
+            let address = endpoint.address.as_ref().unwrap();
+            assert_eq!(
+                address.location().unwrap(),
+                "http://wsf.cdyne.com/WeatherWS/Weather.asmx"
+            );
+            assert_eq!(Some(address.protocol()), endpoint.protocol);
+        }
+    }
+
+    #[test]
+    fn address_of_each_extension() {
+        let text = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+                xmlns:soap="http://schemas.xmlsoap.org/wsdl/soap/"
+                xmlns:http="http://schemas.xmlsoap.org/wsdl/http/"
+                xmlns:a="urn:a" targetNamespace="urn:a">
+              <binding name="Binding"/>
+              <service name="Service">
+                <port name="Soap" binding="a:Binding">
+                  <soap:address location="http://example.com/soap"/>
+                </port>
+                <port name="Http" binding="a:Binding">
+                  <http:address location="http://example.com/http"/>
+                </port>
+                <port name="Other" binding="a:Binding">
+                  <a:address location="urn:elsewhere"/>
+                </port>
+              </service>
+            </definitions>"#;
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+
+        let service = definitions.services().unwrap().next().unwrap();
+        let mut ports = service.ports().unwrap();
+
+        let address = ports.next().unwrap().address().unwrap().unwrap();
+        assert!(matches!(address, WsAddress::Soap(_)));
+        assert_eq!(address.location().unwrap(), "http://example.com/soap");
+        assert_eq!(address.protocol(), WsProtocol::Soap11);
+        assert_eq!(address.node().tag_name().name(), "address");
+
+        let address = ports.next().unwrap().address().unwrap().unwrap();
+        assert!(matches!(address, WsAddress::Http(_)));
+        assert_eq!(address.location().unwrap(), "http://example.com/http");
+        assert_eq!(address.protocol(), WsProtocol::Http);
+
+        assert!(ports.next().unwrap().address().unwrap().is_none());
+
+        let endpoints = definitions.endpoints().unwrap();
+        assert_eq!(endpoints.len(), 3);
+        assert!(endpoints[2].address.is_none());
+        assert_eq!(endpoints[2].protocol, None);
+    }
+}
@@ -0,0 +1,209 @@
+use std::any::Any;
+use std::collections::HashMap;
+
+use roxmltree::{Attribute, ExpandedName, Node};
+
+use crate::wsdl::{invalid_attribute, Result, WsErrorType};
+use crate::WsError;
+
+/// An extensibility element: a child element of a WSDL component that
+/// belongs to a foreign namespace, such as `soap:binding` or a vendor's
+/// proprietary annotations.
+#[derive(Debug, Clone)]
+pub struct WsExtension<'a, 'input>(pub(crate) Node<'a, 'input>);
+
+impl<'a, 'input> WsExtension<'a, 'input> {
+    /// Retrieve the expanded name of the element.
+    pub fn name(&self) -> ExpandedName<'a, 'input> {
+        self.0.tag_name()
+    }
+
+    /// Retrieve the namespace of the element, if any.
+    pub fn namespace(&self) -> Option<&'a str> {
+        self.0.tag_name().namespace()
+    }
+
+    /// Retrieve the attributes of the element.
+    pub fn attributes(&self) -> impl Iterator<Item = Attribute<'a, 'input>> {
+        self.0.attributes()
+    }
+
+    /// Retrieve the value of an attribute of the element.
+    pub fn attribute<'n, 'm>(&self, name: impl Into<ExpandedName<'n, 'm>>) -> Option<&'a str> {
+        self.0.attribute(name)
+    }
+
+    /// Retrieve the `wsdl:required` flag of the element. Required extensions
+    /// must be understood by a consumer, or the component they belong to must
+    /// be ignored.
+    pub fn required(&self) -> Result<bool> {
+        match self
+            .0
+            .attribute(("http://schemas.xmlsoap.org/wsdl/", "required"))
+        {
+            Some("true" | "1") => Ok(true),
+            Some("false" | "0") | None => Ok(false),
+            Some(_) => Err(invalid_attribute(self.0, "required")),
+        }
+    }
+
+    /// Decode the element with the decoder registered for its namespace, if any.
+    pub fn decode(&self, registry: &WsExtensionRegistry) -> Option<Result<Box<dyn Any>>> {
+        registry.decode(self)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// The error type of a [WsExtensionRegistry] decoder.
+pub type WsExtensionError = Box<dyn std::error::Error + Send + Sync>;
+
+type Decoder =
+    Box<dyn Fn(&WsExtension) -> std::result::Result<Box<dyn Any>, WsExtensionError> + Send + Sync>;
+
+/// A set of decoders that turn extensibility elements into typed values,
+/// keyed by the namespace of the elements they understand. This lets
+/// downstream crates support binding vocabularies this library does not.
+#[derive(Default)]
+pub struct WsExtensionRegistry {
+    decoders: HashMap<String, Decoder>,
+}
+
+impl WsExtensionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the decoder for elements of `namespace`, replacing any
+    /// previously registered for it.
+    pub fn register<T, F>(&mut self, namespace: impl Into<String>, decoder: F) -> &mut Self
+    where
+        T: Any,
+        F: Fn(&WsExtension) -> std::result::Result<T, WsExtensionError> + Send + Sync + 'static,
+    {
+        self.decoders.insert(
+            namespace.into(),
+            Box::new(move |e| decoder(e).map(|v| Box::new(v) as Box<dyn Any>)),
+        );
+        self
+    }
+
+    /// Determine whether a decoder is registered for `namespace`.
+    pub fn understands(&self, namespace: &str) -> bool {
+        self.decoders.contains_key(namespace)
+    }
+
+    /// Decode an extensibility element with the decoder registered for its
+    /// namespace. Returns `None` if no decoder is registered.
+    pub fn decode(&self, extension: &WsExtension) -> Option<Result<Box<dyn Any>>> {
+        let decoder = self.decoders.get(extension.namespace()?)?;
+
+        Some(
+            decoder(extension)
+                .map_err(|e| WsError::new(extension.0, WsErrorType::ExtensionDecode(e))),
+        )
+    }
+
+    /// Decode an extensibility element into a value of type `T`. Returns
+    /// `None` if no decoder is registered for its namespace, or if the
+    /// decoder produces a different type.
+    pub fn decode_as<T: Any>(&self, extension: &WsExtension) -> Option<Result<T>> {
+        match self.decode(extension)? {
+            Ok(value) => value.downcast().ok().map(|v| Ok(*v)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+impl std::fmt::Debug for WsExtensionRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.decoders.keys()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WsDefinitions;
+    use roxmltree::Document;
+
+    const VENDOR_NS: &str = "urn:vendor";
+
+    #[derive(Debug, PartialEq)]
+    struct Timeout(u32);
+
+    fn registry() -> WsExtensionRegistry {
+        let mut registry = WsExtensionRegistry::new();
+        registry.register(VENDOR_NS, |e| {
+            let seconds = e.attribute("seconds").ok_or("missing seconds")?;
+            Ok(Timeout(seconds.parse()?))
+        });
+        registry
+    }
+
+    #[test]
+    fn decode_registered_extensions() {
+        let text = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+                xmlns:wsdl="http://schemas.xmlsoap.org/wsdl/"
+                xmlns:soap="http://schemas.xmlsoap.org/wsdl/soap/"
+                xmlns:v="urn:vendor">
+              <binding name="Binding">
+                <soap:binding transport="http://schemas.xmlsoap.org/soap/http"/>
+                <v:timeout seconds="30" wsdl:required="true"/>
+                <v:timeout seconds="soon"/>
+                <v:timeout wsdl:required="maybe"/>
+              </binding>
+            </definitions>"#;
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let binding = definitions.bindings().unwrap().next().unwrap();
+
+        let registry = registry();
+        assert!(registry.understands(VENDOR_NS));
+        assert!(!registry.understands("http://schemas.xmlsoap.org/wsdl/soap/"));
+
+        let extensions: Vec<_> = binding.extensions().collect();
+        assert_eq!(extensions.len(), 4);
+
+        // No decoder is registered for SOAP.
+        let soap = &extensions[0];
+        assert_eq!(soap.name().name(), "binding");
+        assert!(!soap.required().unwrap());
+        assert!(soap.decode(&registry).is_none());
+
+        let timeout = &extensions[1];
+        assert_eq!(timeout.namespace(), Some(VENDOR_NS));
+        assert_eq!(timeout.attributes().count(), 2);
+        assert!(timeout.required().unwrap());
+        let value = timeout.decode(&registry).unwrap().unwrap();
+        assert_eq!(value.downcast_ref::<Timeout>(), Some(&Timeout(30)));
+        let value = registry.decode_as::<Timeout>(timeout).unwrap();
+        assert_eq!(value.unwrap(), Timeout(30));
+        assert!(registry.decode_as::<String>(timeout).is_none());
+
+        let error = registry.decode(&extensions[2]).unwrap().unwrap_err();
+        assert!(matches!(
+            error.error_type(),
+            WsErrorType::ExtensionDecode(_)
+        ));
+        let error = registry.decode(&extensions[3]).unwrap().unwrap_err();
+        assert!(error.to_string().contains("missing seconds"), "{error}");
+        assert!(extensions[3].required().is_err());
+    }
+
+    #[test]
+    fn share_registry_across_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<WsExtensionRegistry>();
+
+        let registry = std::sync::Arc::new(registry());
+        let shared = registry.clone();
+        std::thread::spawn(move || assert!(shared.understands(VENDOR_NS)))
+            .join()
+            .unwrap();
+        assert_eq!(format!("{:?}", registry), "{\"urn:vendor\"}");
+    }
+}
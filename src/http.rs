@@ -0,0 +1,216 @@
+use roxmltree::Node;
+
+use crate::resolver::percent_encode;
+use crate::wsdl::{required_attribute, Result};
+use crate::{WsBinding, WsBindingMessage, WsBindingOperation, WsServicePort};
+
+// The HTTP GET & POST binding extensions, as defined in section 4 of the WSDL 1.1 specification.
+const HTTP_NS: &str = "http://schemas.xmlsoap.org/wsdl/http/";
+
+fn http_child<'a, 'input>(node: Node<'a, 'input>, tag: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|n| n.has_tag_name((HTTP_NS, tag)))
+}
+
+/// How the parts of an input message are carried in the request URL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpUrlEncoding {
+    /// `http:urlEncoded`: the parts are appended as a `name=value` query string.
+    UrlEncoded,
+    /// `http:urlReplacement`: the parts replace the `(name)` placeholders of
+    /// the operation's location.
+    UrlReplacement,
+}
+
+/// The `http:binding` element, which marks a [WsBinding] as an HTTP binding.
+#[derive(Debug, Clone)]
+pub struct HttpBinding<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> HttpBinding<'a, 'input> {
+    /// Retrieve the HTTP verb used by the binding's operations, such as `GET` or `POST`.
+    pub fn verb(&self) -> Result<&'a str> {
+        required_attribute(self.0, "verb")
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// The `http:operation` element of a [WsBindingOperation].
+#[derive(Debug, Clone)]
+pub struct HttpOperation<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> HttpOperation<'a, 'input> {
+    /// Retrieve the location of the operation, relative to the port's `http:address`.
+    pub fn location(&self) -> Result<&'a str> {
+        required_attribute(self.0, "location")
+    }
+
+    /// Build the URL of a request relative to the port's `http:address`,
+    /// carrying the given part values according to the input's `encoding`.
+    /// Values are percent-encoded.
+    pub fn url(&self, encoding: Option<HttpUrlEncoding>, parts: &[(&str, &str)]) -> Result<String> {
+        let location = self.location()?;
+
+        Ok(match encoding {
+            Some(HttpUrlEncoding::UrlReplacement) => parts
+                .iter()
+                .fold(location.to_string(), |url, (name, value)| {
+                    url.replace(&format!("({})", name), &percent_encode(value))
+                }),
+            Some(HttpUrlEncoding::UrlEncoded) if !parts.is_empty() => {
+                let query = parts
+                    .iter()
+                    .map(|(name, value)| {
+                        format!("{}={}", percent_encode(name), percent_encode(value))
+                    })
+                    .collect::<Vec<_>>()
+                    .join("&");
+
+                let separator = if location.contains('?') { '&' } else { '?' };
+                format!("{}{}{}", location, separator, query)
+            }
+            _ => location.to_string(),
+        })
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// The `http:address` element of a [WsServicePort].
+#[derive(Debug, Clone)]
+pub struct HttpAddress<'a, 'input>(pub(crate) Node<'a, 'input>);
+
+impl<'a, 'input> HttpAddress<'a, 'input> {
+    /// Retrieve the base URL of the port. Operation locations are relative to this.
+    pub fn location(&self) -> Result<&'a str> {
+        required_attribute(self.0, "location")
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+impl<'a, 'input> WsBinding<'a, 'input> {
+    /// Retrieve the `http:binding` of this binding, if it is an HTTP binding.
+    pub fn http_binding(&self) -> Result<Option<HttpBinding<'a, 'input>>> {
+        Ok(http_child(self.0, "binding").map(HttpBinding))
+    }
+}
+
+impl<'a, 'input> WsBindingOperation<'a, 'input> {
+    /// Retrieve the `http:operation` of this operation, if any.
+    pub fn http_operation(&self) -> Result<Option<HttpOperation<'a, 'input>>> {
+        Ok(http_child(self.0, "operation").map(HttpOperation))
+    }
+}
+
+impl<'a, 'input> WsBindingMessage<'a, 'input> {
+    /// Retrieve how the parts of this (input) message are carried in the URL, if specified.
+    pub fn http_url_encoding(&self) -> Result<Option<HttpUrlEncoding>> {
+        Ok(self.0.children().find_map(|n| {
+            if n.has_tag_name((HTTP_NS, "urlEncoded")) {
+                Some(HttpUrlEncoding::UrlEncoded)
+            } else if n.has_tag_name((HTTP_NS, "urlReplacement")) {
+                Some(HttpUrlEncoding::UrlReplacement)
+            } else {
+                None
+            }
+        }))
+    }
+}
+
+impl<'a, 'input> WsServicePort<'a, 'input> {
+    /// Retrieve the `http:address` of this port, if any.
+    pub fn http_address(&self) -> Result<Option<HttpAddress<'a, 'input>>> {
+        Ok(http_child(self.0, "address").map(HttpAddress))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WsDefinitions;
+    use roxmltree::Document;
+
+    fn binding<'a, 'input>(
+        definitions: &WsDefinitions<'a, 'input>,
+        name: &str,
+    ) -> WsBinding<'a, 'input> {
+        let mut bindings = definitions.bindings().unwrap();
+        bindings.find(|b| b.name().unwrap() == name).unwrap()
+    }
+
+    #[test]
+    fn read_http_extensions() {
+        let text = include_str!("../assets/weather/weather.wsdl");
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+
+        let get = binding(&definitions, "WeatherHttpGet");
+        assert_eq!(get.http_binding().unwrap().unwrap().verb().unwrap(), "GET");
+        let post = binding(&definitions, "WeatherHttpPost");
+        assert_eq!(
+            post.http_binding().unwrap().unwrap().verb().unwrap(),
+            "POST"
+        );
+        let soap = binding(&definitions, "WeatherSoap");
+        assert!(soap.http_binding().unwrap().is_none());
+
+        let operation = get.operations().unwrap().next().unwrap();
+        let http = operation.http_operation().unwrap().unwrap();
+        assert_eq!(http.location().unwrap(), "/GetWeatherInformation");
+
+        let input = operation.input().unwrap().unwrap();
+        let encoding = input.http_url_encoding().unwrap();
+        assert_eq!(encoding, Some(HttpUrlEncoding::UrlEncoded));
+        let url = http.url(encoding, &[("ZIP", "10001"), ("a b", "c&d")]);
+        assert_eq!(url.unwrap(), "/GetWeatherInformation?ZIP=10001&a%20b=c%26d");
+        assert_eq!(http.url(encoding, &[]).unwrap(), "/GetWeatherInformation");
+
+        let operation = post.operations().unwrap().next().unwrap();
+        let input = operation.input().unwrap().unwrap();
+        assert_eq!(input.http_url_encoding().unwrap(), None);
+    }
+
+    #[test]
+    fn replace_url_placeholders() {
+        let text = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+                xmlns:http="http://schemas.xmlsoap.org/wsdl/http/">
+              <binding name="Binding">
+                <http:binding verb="GET"/>
+                <operation name="Forecast">
+                  <http:operation location="/forecast/(city)/(day)?units=metric"/>
+                  <input><http:urlReplacement/></input>
+                </operation>
+              </binding>
+            </definitions>"#;
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+
+        let operation = binding(&definitions, "Binding")
+            .operations()
+            .unwrap()
+            .next()
+            .unwrap();
+        let http = operation.http_operation().unwrap().unwrap();
+        let encoding = operation.input().unwrap().unwrap().http_url_encoding();
+        assert_eq!(encoding.unwrap(), Some(HttpUrlEncoding::UrlReplacement));
+
+        let url = http.url(
+            Some(HttpUrlEncoding::UrlReplacement),
+            &[("city", "New York"), ("day", "1")],
+        );
+        assert_eq!(url.unwrap(), "/forecast/New%20York/1?units=metric");
+
+        // A query string is extended rather than started.
+        let url = http.url(Some(HttpUrlEncoding::UrlEncoded), &[("day", "1")]);
+        assert_eq!(url.unwrap(), "/forecast/(city)/(day)?units=metric&day=1");
+    }
+}
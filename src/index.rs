@@ -0,0 +1,417 @@
+use std::collections::HashMap;
+
+use roxmltree::{ExpandedName, Node};
+
+use crate::wsdl::{Scope, WsComponentKind};
+use crate::xsd::{Element, Schemas, Type};
+use crate::{WsBinding, WsDefinitions, WsMessage, WsPortType, WsService, WsdlSet};
+
+// The definitions of one kind of component, by namespace and local name.
+// Components without a namespace are filed under the empty namespace.
+type Symbols<'a, 'input> = HashMap<&'a str, HashMap<&'a str, Vec<Node<'a, 'input>>>>;
+
+/// An index of the named components of one or more WSDL documents and of
+/// their embedded schemas, built once so that references resolve without
+/// scanning the documents.
+///
+/// Wrappers retrieved through [Self::definitions] resolve their references
+/// through the index, which keeps traversing large documents linear.
+#[derive(Debug, Clone)]
+pub struct WsIndex<'a, 'input> {
+    definitions: Vec<WsDefinitions<'a, 'input>>,
+    components: HashMap<WsComponentKind, Symbols<'a, 'input>>,
+    // Port type operations, keyed by the expanded name of the port type and
+    // the name of the operation. Only the first of overloaded operations is kept.
+    operations: HashMap<(Option<&'a str>, &'a str, &'a str), Node<'a, 'input>>,
+    schemas: Schemas<'a, 'input>,
+}
+
+impl<'a, 'input> WsIndex<'a, 'input> {
+    /// Index the given definitions. If they were retrieved from a [WsdlSet],
+    /// every document of the set is indexed.
+    pub fn new(definitions: &WsDefinitions<'a, 'input>) -> Self {
+        match definitions.1 {
+            Scope::Set(set) => Self::from_set(set),
+            Scope::Index(index) => index.clone(),
+            Scope::Document => Self::build(
+                vec![definitions.clone()],
+                Schemas::from_definitions(definitions),
+            ),
+        }
+    }
+
+    /// Index every document of a set.
+    pub fn from_set(set: &'a WsdlSet<'input>) -> Self {
+        Self::build(set.definitions().collect(), Schemas::from_set(set))
+    }
+
+    fn build(definitions: Vec<WsDefinitions<'a, 'input>>, schemas: Schemas<'a, 'input>) -> Self {
+        let mut index = Self {
+            definitions,
+            components: HashMap::new(),
+            operations: HashMap::new(),
+            schemas,
+        };
+
+        for definitions in index.definitions.clone() {
+            index.add(&definitions);
+        }
+
+        index
+    }
+
+    fn add(&mut self, definitions: &WsDefinitions<'a, 'input>) {
+        let namespace = definitions.target_namespace();
+
+        for node in definitions.0.children() {
+            if node.tag_name().namespace() != Some("http://schemas.xmlsoap.org/wsdl/") {
+                continue;
+            }
+
+            let kind = WsComponentKind::DEFINITIONS
+                .into_iter()
+                .find(|k| k.tag() == node.tag_name().name());
+
+            let (Some(kind), Some(name)) = (kind, node.attribute("name")) else {
+                continue;
+            };
+
+            self.components
+                .entry(kind)
+                .or_default()
+                .entry(namespace.unwrap_or_default())
+                .or_default()
+                .entry(name)
+                .or_default()
+                .push(node);
+
+            if kind != WsComponentKind::PortType {
+                continue;
+            }
+
+            for operation in node
+                .children()
+                .filter(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "operation")))
+            {
+                if let Some(operation_name) = operation.attribute("name") {
+                    self.operations
+                        .entry((namespace, name, operation_name))
+                        .or_insert(operation);
+                }
+            }
+        }
+    }
+
+    /// Retrieve every definition of a component. More than one means the
+    /// component is defined more than once.
+    pub(crate) fn find<'n>(
+        &self,
+        kind: WsComponentKind,
+        name: ExpandedName<'n, 'n>,
+    ) -> &[Node<'a, 'input>] {
+        self.components
+            .get(&kind)
+            .and_then(|s| s.get(name.namespace().unwrap_or_default()))
+            .and_then(|s| s.get(name.name()))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    // Retrieve the first definition of a component.
+    fn first<'n>(
+        &self,
+        kind: WsComponentKind,
+        name: impl Into<ExpandedName<'n, 'n>>,
+    ) -> Option<Node<'a, 'input>> {
+        self.find(kind, name.into()).first().copied()
+    }
+
+    /// Retrieve an operation of a port type, by the expanded name of the
+    /// port type and the name of the operation.
+    pub(crate) fn operation(
+        &self,
+        port_type: (Option<&str>, &str),
+        name: &str,
+    ) -> Option<Node<'a, 'input>> {
+        self.operations
+            .get(&(port_type.0, port_type.1, name))
+            .copied()
+    }
+
+    /// Retrieve the indexed definitions. References made by these, and
+    /// by every wrapper retrieved through them, resolve through the index.
+    pub fn definitions(&self) -> impl Iterator<Item = WsDefinitions<'_, 'input>> {
+        self.definitions
+            .iter()
+            .map(|d| WsDefinitions(d.0, Scope::Index(self)))
+    }
+
+    /// Retrieve the definitions the index was built from. For a set, this is
+    /// the document the set was loaded from.
+    pub fn root(&self) -> Option<WsDefinitions<'_, 'input>> {
+        self.definitions().next()
+    }
+
+    /// Find a message by its expanded name.
+    pub fn message<'n>(
+        &self,
+        name: impl Into<ExpandedName<'n, 'n>>,
+    ) -> Option<WsMessage<'_, 'input>> {
+        self.first(WsComponentKind::Message, name)
+            .map(|n| WsMessage(n, Scope::Index(self)))
+    }
+
+    /// Find a port type by its expanded name.
+    pub fn port_type<'n>(
+        &self,
+        name: impl Into<ExpandedName<'n, 'n>>,
+    ) -> Option<WsPortType<'_, 'input>> {
+        self.first(WsComponentKind::PortType, name)
+            .map(|n| WsPortType(n, Scope::Index(self)))
+    }
+
+    /// Find a binding by its expanded name.
+    pub fn binding<'n>(
+        &self,
+        name: impl Into<ExpandedName<'n, 'n>>,
+    ) -> Option<WsBinding<'_, 'input>> {
+        self.first(WsComponentKind::Binding, name)
+            .map(|n| WsBinding(n, Scope::Index(self)))
+    }
+
+    /// Find a service by its expanded name.
+    pub fn service<'n>(
+        &self,
+        name: impl Into<ExpandedName<'n, 'n>>,
+    ) -> Option<WsService<'_, 'input>> {
+        self.first(WsComponentKind::Service, name)
+            .map(|n| WsService(n, Scope::Index(self)))
+    }
+
+    /// Retrieve the components of the embedded schemas, along with those of
+    /// the schemas they refer to and the standalone schemas of a set.
+    pub fn schemas(&self) -> &Schemas<'a, 'input> {
+        &self.schemas
+    }
+
+    /// Find a global element declaration of the embedded schemas by its
+    /// expanded name.
+    pub fn schema_element<'n>(
+        &self,
+        name: impl Into<ExpandedName<'n, 'n>>,
+    ) -> Option<Element<'a, 'input>> {
+        self.schemas.element(name)
+    }
+
+    /// Find a simple or complex type definition of the embedded schemas by
+    /// its expanded name.
+    pub fn schema_type<'n>(
+        &self,
+        name: impl Into<ExpandedName<'n, 'n>>,
+    ) -> Option<Type<'a, 'input>> {
+        self.schemas.type_definition(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MemoryResolver, WsdlSources};
+
+    const SERVICE: &str = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+            xmlns:msg="urn:messages" xmlns:tns="urn:service" targetNamespace="urn:service">
+          <import namespace="urn:messages" location="messages.wsdl"/>
+          <portType name="Port">
+            <operation name="Echo">
+              <input message="msg:Request"/>
+              <output message="msg:Response"/>
+              <fault name="Failed" message="msg:Twice"/>
+            </operation>
+            <operation name="Echo">
+              <input message="msg:Response"/>
+            </operation>
+            <operation name="Missing">
+              <input message="tns:Request"/>
+              <output message="msg:Port"/>
+            </operation>
+          </portType>
+          <binding name="Binding" type="tns:Port">
+            <operation name="Echo"/>
+            <operation name="Missing"/>
+            <operation name="Unknown"/>
+          </binding>
+          <binding name="Dangling" type="msg:Port">
+            <operation name="Echo"/>
+          </binding>
+          <service name="Service">
+            <port name="Bound" binding="tns:Binding"/>
+            <port name="Dangling" binding="tns:Other"/>
+          </service>
+        </definitions>"#;
+
+    // Also declares a port type named like the one of the service, in
+    // another namespace.
+    const MESSAGES: &str = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+            xmlns:xs="http://www.w3.org/2001/XMLSchema" targetNamespace="urn:messages">
+          <types>
+            <xs:schema targetNamespace="urn:schema">
+              <xs:element name="Echo"/>
+              <xs:complexType name="Echo"/>
+            </xs:schema>
+          </types>
+          <message name="Request"/>
+          <message name="Response"/>
+          <message name="Twice"/>
+          <message name="Twice"/>
+          <portType name="Port">
+            <operation name="Echo"/>
+          </portType>
+        </definitions>"#;
+
+    fn sources() -> WsdlSources {
+        let mut resolver = MemoryResolver::new();
+        resolver
+            .insert("service.wsdl", SERVICE)
+            .insert("messages.wsdl", MESSAGES);
+        WsdlSources::load(resolver, "service.wsdl").unwrap()
+    }
+
+    // Resolve every reference made by the definitions, keeping the error
+    // messages of those that fail.
+    fn references<'a, 'input>(
+        definitions: WsDefinitions<'a, 'input>,
+    ) -> Vec<std::result::Result<Node<'a, 'input>, String>> {
+        fn node<'a, 'input, T>(
+            result: crate::wsdl::Result<T>,
+            node: impl Fn(T) -> Node<'a, 'input>,
+        ) -> std::result::Result<Node<'a, 'input>, String> {
+            result.map(node).map_err(|e| e.error_type().to_string())
+        }
+
+        let mut references = Vec::new();
+
+        for operation in definitions
+            .port_types()
+            .unwrap()
+            .flat_map(|p| p.operations().unwrap())
+        {
+            // Operations without an input or output resolve to themselves.
+            for message in [operation.input(), operation.output()] {
+                references.push(node(message, |m| m.map_or(operation.node(), |m| m.node())));
+            }
+            for fault in operation.faults().unwrap() {
+                references.push(node(fault.message(), |m| m.node()));
+            }
+        }
+
+        for binding in definitions.bindings().unwrap() {
+            references.push(node(binding.port_type(), |p| p.node()));
+            for operation in binding.operations().unwrap() {
+                references.push(node(operation.port_operation(), |o| o.node()));
+            }
+        }
+
+        for port in definitions
+            .services()
+            .unwrap()
+            .flat_map(|s| s.ports().unwrap())
+        {
+            references.push(node(port.binding(), |b| b.node()));
+        }
+
+        references
+    }
+
+    #[test]
+    fn resolve_like_a_scan() {
+        for text in [
+            include_str!("../assets/hello/hello.wsdl"),
+            include_str!("../assets/weather/weather.wsdl"),
+            include_str!("../assets/aic/workflow_wsdl.xml"),
+            SERVICE,
+        ] {
+            let document = roxmltree::Document::parse(text).unwrap();
+            let definitions = WsDefinitions::from_document(&document).unwrap();
+            let index = WsIndex::new(&definitions);
+
+            assert_eq!(references(index.root().unwrap()), references(definitions));
+        }
+
+        let sources = sources();
+        let set = WsdlSet::parse(&sources).unwrap();
+        let index = WsIndex::from_set(&set);
+        assert_eq!(index.definitions().count(), 2);
+
+        let expected = references(set.root().unwrap());
+        assert!(expected.iter().any(|r| r.is_ok()));
+        assert!(expected.iter().any(|r| r.is_err()));
+        assert_eq!(references(index.root().unwrap()), expected);
+        assert_eq!(
+            references(WsIndex::new(&set.root().unwrap()).root().unwrap()),
+            expected
+        );
+    }
+
+    #[test]
+    fn find_components_by_expanded_name() {
+        let sources = sources();
+        let set = WsdlSet::parse(&sources).unwrap();
+        let index = WsIndex::from_set(&set);
+
+        let request = index.message(("urn:messages", "Request")).unwrap();
+        assert_eq!(request.name().unwrap(), "Request");
+        assert!(index.message(("urn:service", "Request")).is_none());
+        assert!(index.message(("urn:messages", "Port")).is_none());
+
+        let service = index.port_type(("urn:service", "Port")).unwrap();
+        let messages = index.port_type(("urn:messages", "Port")).unwrap();
+        assert_ne!(service.node(), messages.node());
+
+        assert!(index.binding(("urn:service", "Binding")).is_some());
+        assert!(index.service(("urn:service", "Service")).is_some());
+
+        let element = index.schema_element(("urn:schema", "Echo")).unwrap();
+        let typ = index.schema_type(("urn:schema", "Echo")).unwrap();
+        assert!(element.node().has_tag_name("element"));
+        assert!(typ.node().has_tag_name("complexType"));
+        assert!(index.schema_element(("urn:messages", "Echo")).is_none());
+
+        // Every definition of a duplicate is kept.
+        let twice = index.find(WsComponentKind::Message, ("urn:messages", "Twice").into());
+        assert_eq!(twice.len(), 2);
+        assert_eq!(
+            index.message(("urn:messages", "Twice")).map(|m| m.node()),
+            Some(twice[0])
+        );
+    }
+
+    #[test]
+    fn map_operations_by_port_type() {
+        let sources = sources();
+        let set = WsdlSet::parse(&sources).unwrap();
+        let index = WsIndex::from_set(&set);
+
+        // Only the first of overloaded operations is kept.
+        let echo = index
+            .operation((Some("urn:service"), "Port"), "Echo")
+            .unwrap();
+        let first = index
+            .port_type(("urn:service", "Port"))
+            .unwrap()
+            .operations()
+            .unwrap()
+            .next()
+            .unwrap();
+        assert_eq!(echo, first.node());
+
+        let other = index
+            .operation((Some("urn:messages"), "Port"), "Echo")
+            .unwrap();
+        assert_ne!(other, echo);
+
+        assert!(index
+            .operation((Some("urn:service"), "Port"), "Unknown")
+            .is_none());
+        assert!(index.operation((None, "Port"), "Echo").is_none());
+    }
+}
@@ -1,10 +1,46 @@
 #![doc = include_str!("../README.md")]
+mod description;
+mod endpoint;
+mod extension;
+mod http;
+mod index;
+mod mime;
+pub mod owned;
+mod profile;
+mod resolver;
+mod set;
+mod soap;
+mod validate;
 mod wsdl;
+mod wsdl2;
+pub mod xsd;
 
+pub use self::description::{
+    WsDescription, WsEndpointInfo, WsOperationInfo, WsServiceInfo, WsdlVersion,
+};
+pub use self::endpoint::{WsAddress, WsEndpoint, WsProtocol};
+pub use self::extension::{WsExtension, WsExtensionError, WsExtensionRegistry};
+pub use self::http::{HttpAddress, HttpBinding, HttpOperation, HttpUrlEncoding};
+pub use self::index::WsIndex;
+pub use self::mime::{MimeContent, MimeElement, MimeMultipartRelated, MimePart, MimeXml};
+pub use self::profile::BpAssertion;
+pub use self::resolver::{CatalogResolver, FileResolver, MemoryResolver, WsResolver, WsSource};
+pub use self::set::{WsLoadError, WsdlSet, WsdlSources};
+pub use self::soap::{
+    SoapAddress, SoapBinding, SoapBody, SoapFault, SoapHeader, SoapHeaderFault, SoapOperation,
+    SoapStyle, SoapUse, SoapVersion,
+};
+pub use self::validate::{WsDiagnostic, WsSeverity};
 pub use self::wsdl::{
-    WsBinding, WsDefinitions, WsError, WsMessage, WsMessagePart, WsPortOperation, WsPortType,
-    WsService, WsServicePort, WsTypes,
+    OperationKind, WsBinding, WsBindingMessage, WsBindingOperation, WsComponentKind, WsDefinitions,
+    WsError, WsErrorMalformedType, WsErrorType, WsImport, WsLocation, WsMessage, WsMessagePart,
+    WsOperationFault, WsPartDefinition, WsPartKind, WsPortOperation, WsPortType, WsService,
+    WsServicePort, WsTypes,
+};
+pub use self::wsdl2::{
+    MessageExchangePattern, Ws2Binding, Ws2BindingOperation, Ws2Description, Ws2Endpoint,
+    Ws2FaultReference, Ws2Interface, Ws2InterfaceFault, Ws2InterfaceOperation, Ws2MessageReference,
+    Ws2Service,
 };
-
 /// Re-export the roxmltree crate.
 pub use roxmltree;
@@ -0,0 +1,240 @@
+use roxmltree::Node;
+
+use crate::wsdl::{required_attribute, Result};
+use crate::{SoapBody, WsBindingMessage};
+
+// The MIME binding extensions, as defined in section 5 of the WSDL 1.1 specification.
+const MIME_NS: &str = "http://schemas.xmlsoap.org/wsdl/mime/";
+
+/// A MIME binding extension element of a [WsBindingMessage].
+#[derive(Debug, Clone)]
+pub enum MimeElement<'a, 'input> {
+    Content(MimeContent<'a, 'input>),
+    MimeXml(MimeXml<'a, 'input>),
+    MultipartRelated(MimeMultipartRelated<'a, 'input>),
+}
+
+impl<'a, 'input> MimeElement<'a, 'input> {
+    fn from_node(node: Node<'a, 'input>) -> Option<Self> {
+        if node.tag_name().namespace() != Some(MIME_NS) {
+            return None;
+        }
+
+        match node.tag_name().name() {
+            "content" => Some(Self::Content(MimeContent(node))),
+            "mimeXml" => Some(Self::MimeXml(MimeXml(node))),
+            "multipartRelated" => Some(Self::MultipartRelated(MimeMultipartRelated(node))),
+            _ => None,
+        }
+    }
+
+    /// Retrieve the media type of the message body, if known.
+    pub fn content_type(&self) -> Option<&'a str> {
+        match self {
+            Self::Content(c) => c.content_type(),
+            Self::MimeXml(_) => Some("text/xml"),
+            Self::MultipartRelated(_) => Some("multipart/related"),
+        }
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        match self {
+            Self::Content(c) => c.node(),
+            Self::MimeXml(x) => x.node(),
+            Self::MultipartRelated(m) => m.node(),
+        }
+    }
+}
+
+/// The `mime:content` element, which carries a message part as an arbitrary MIME type.
+#[derive(Debug, Clone)]
+pub struct MimeContent<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> MimeContent<'a, 'input> {
+    /// Retrieve the name of the message part carried. If unspecified, the
+    /// message has a single part.
+    pub fn part(&self) -> Option<&'a str> {
+        self.0.attribute("part")
+    }
+
+    /// Retrieve the media type, such as `application/x-www-form-urlencoded`.
+    /// This may contain wildcards, and is unconstrained if unspecified.
+    pub fn content_type(&self) -> Option<&'a str> {
+        self.0.attribute("type")
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// The `mime:mimeXml` element, which carries a message part as a bare XML
+/// document, with no SOAP envelope.
+#[derive(Debug, Clone)]
+pub struct MimeXml<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> MimeXml<'a, 'input> {
+    /// Retrieve the name of the message part carried. If unspecified, the
+    /// message has a single part.
+    pub fn part(&self) -> Option<&'a str> {
+        self.0.attribute("part")
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// The `mime:multipartRelated` element, which carries message parts in the
+/// parts of a `multipart/related` body.
+#[derive(Debug, Clone)]
+pub struct MimeMultipartRelated<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> MimeMultipartRelated<'a, 'input> {
+    /// Retrieve the MIME parts, in order.
+    pub fn parts(&self) -> impl Iterator<Item = MimePart<'a, 'input>> {
+        self.0
+            .children()
+            .filter(|n| n.has_tag_name((MIME_NS, "part")))
+            .map(MimePart)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// A `mime:part` of a [MimeMultipartRelated].
+#[derive(Debug, Clone)]
+pub struct MimePart<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> MimePart<'a, 'input> {
+    /// Retrieve the name of the MIME part.
+    pub fn name(&self) -> Result<&'a str> {
+        required_attribute(self.0, "name")
+    }
+
+    /// Retrieve the MIME elements of this part. Multiple `mime:content`
+    /// elements are alternatives to one another.
+    pub fn elements(&self) -> impl Iterator<Item = MimeElement<'a, 'input>> {
+        self.0.children().filter_map(MimeElement::from_node)
+    }
+
+    /// Retrieve the `soap:body` of this part, for the part that carries the
+    /// SOAP envelope.
+    pub fn soap_body(&self) -> Result<Option<SoapBody<'a, 'input>>> {
+        Ok(self
+            .0
+            .children()
+            .find(|n| {
+                n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/soap/", "body"))
+                    || n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/soap12/", "body"))
+            })
+            .map(SoapBody))
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+impl<'a, 'input> WsBindingMessage<'a, 'input> {
+    /// Retrieve the MIME elements that describe the body of this message.
+    /// Multiple `mime:content` elements are alternatives to one another.
+    pub fn mime(&self) -> Result<impl Iterator<Item = MimeElement<'a, 'input>>> {
+        Ok(self.0.children().filter_map(MimeElement::from_node))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WsDefinitions;
+    use roxmltree::Document;
+
+    #[test]
+    fn read_mime_extensions() {
+        let text = include_str!("../assets/weather/weather.wsdl");
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+
+        let mut bindings = definitions.bindings().unwrap();
+        let post = bindings.find(|b| b.name().unwrap() == "WeatherHttpPost");
+        let operation = post.unwrap().operations().unwrap().next().unwrap();
+
+        let input = operation.input().unwrap().unwrap();
+        let mime: Vec<_> = input.mime().unwrap().collect();
+        assert_eq!(mime.len(), 1);
+        match &mime[0] {
+            MimeElement::Content(content) => {
+                assert_eq!(content.part(), None);
+                assert_eq!(
+                    content.content_type(),
+                    Some("application/x-www-form-urlencoded")
+                );
+            }
+            other => panic!("unexpected element {:?}", other),
+        }
+
+        let output = operation.output().unwrap().unwrap();
+        let mime: Vec<_> = output.mime().unwrap().collect();
+        assert_eq!(mime.len(), 1);
+        assert_eq!(mime[0].content_type(), Some("text/xml"));
+        match &mime[0] {
+            MimeElement::MimeXml(xml) => assert_eq!(xml.part(), Some("Body")),
+            other => panic!("unexpected element {:?}", other),
+        }
+    }
+
+    #[test]
+    fn read_multipart_related() {
+        let text = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+                xmlns:soap="http://schemas.xmlsoap.org/wsdl/soap/"
+                xmlns:mime="http://schemas.xmlsoap.org/wsdl/mime/">
+              <binding name="Binding">
+                <operation name="Upload">
+                  <input>
+                    <mime:multipartRelated>
+                      <mime:part name="envelope">
+                        <soap:body parts="id" use="literal"/>
+                      </mime:part>
+                      <mime:part name="image">
+                        <mime:content part="photo" type="image/png"/>
+                        <mime:content part="photo" type="image/jpeg"/>
+                      </mime:part>
+                    </mime:multipartRelated>
+                  </input>
+                </operation>
+              </binding>
+            </definitions>"#;
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+
+        let binding = definitions.bindings().unwrap().next().unwrap();
+        let operation = binding.operations().unwrap().next().unwrap();
+        let input = operation.input().unwrap().unwrap();
+        let mime = input.mime().unwrap().next().unwrap();
+        assert_eq!(mime.content_type(), Some("multipart/related"));
+        let related = match mime {
+            MimeElement::MultipartRelated(related) => related,
+            other => panic!("unexpected element {:?}", other),
+        };
+
+        let parts: Vec<_> = related.parts().collect();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].name().unwrap(), "envelope");
+        let body = parts[0].soap_body().unwrap().unwrap();
+        assert_eq!(body.parts(), Some(vec!["id"]));
+        assert_eq!(parts[0].elements().count(), 0);
+
+        assert_eq!(parts[1].name().unwrap(), "image");
+        assert!(parts[1].soap_body().unwrap().is_none());
+        let types: Vec<_> = parts[1].elements().map(|e| e.content_type()).collect();
+        assert_eq!(types, [Some("image/png"), Some("image/jpeg")]);
+    }
+}
@@ -0,0 +1,886 @@
+//! An owned model of WSDL 1.1 definitions.
+//!
+//! The `Ws*` wrappers borrow the parsed XML document, which keeps them cheap
+//! but ties them to the lifetime of the source text. The types of this module
+//! copy everything out of the document instead, so they can be cached, sent
+//! across threads or returned from functions that own the source.
+//!
+//! References between components are resolved up front, and stored as
+//! indices into the vectors of [Definitions]. For instance, the `port_type`
+//! of a [Binding] indexes [Definitions::port_types].
+//!
+//! # Serialization
+//! With the `serde` feature enabled, every type of this module implements
+//! `Serialize` and `Deserialize`. Structs map to JSON objects whose keys are
+//! their field names, and absent optional values are written as `null`, so
+//! every key is always present. References stay indices. Names are objects
+//! with a `namespace` and a `name`, and enumerations are lowercase strings:
+//!
+//! | Type              | Values                                                            |
+//! |-------------------|-------------------------------------------------------------------|
+//! | [OperationKind]   | `one-way`, `request-response`, `solicit-response`, `notification` |
+//! | [SoapVersion]     | `soap11`, `soap12`                                                |
+//! | [SoapStyle]       | `rpc`, `document`                                                 |
+//! | [SoapUse]         | `literal`, `encoded`                                              |
+//! | [WsProtocol]      | `soap11`, `soap12`, `http`                                        |
+//!
+//! For example, a minimal SOAP 1.1 service serializes as follows:
+//! ```json
+//! {
+//!   "target_namespace": "urn:hello",
+//!   "documentation": null,
+//!   "messages": [
+//!     {
+//!       "name": { "namespace": "urn:hello", "name": "SayHello" },
+//!       "documentation": null,
+//!       "parts": [
+//!         {
+//!           "name": "parameters",
+//!           "documentation": null,
+//!           "element": { "namespace": "urn:hello", "name": "SayHello" },
+//!           "type_name": null
+//!         }
+//!       ]
+//!     }
+//!   ],
+//!   "port_types": [
+//!     {
+//!       "name": { "namespace": "urn:hello", "name": "Hello" },
+//!       "documentation": null,
+//!       "operations": [
+//!         {
+//!           "name": "SayHello",
+//!           "documentation": null,
+//!           "kind": "one-way",
+//!           "input": 0,
+//!           "output": null,
+//!           "faults": [],
+//!           "parameter_order": null
+//!         }
+//!       ]
+//!     }
+//!   ],
+//!   "bindings": [
+//!     {
+//!       "name": { "namespace": "urn:hello", "name": "HelloSoap" },
+//!       "documentation": null,
+//!       "port_type": 0,
+//!       "soap": {
+//!         "version": "soap11",
+//!         "transport": "http://schemas.xmlsoap.org/soap/http",
+//!         "style": "document"
+//!       },
+//!       "operations": [
+//!         {
+//!           "name": "SayHello",
+//!           "documentation": null,
+//!           "operation": 0,
+//!           "soap_action": "",
+//!           "style": "document",
+//!           "input": {
+//!             "name": null,
+//!             "body": { "usage": "literal", "parts": null, "namespace": null },
+//!             "headers": []
+//!           },
+//!           "output": null,
+//!           "faults": []
+//!         }
+//!       ]
+//!     }
+//!   ],
+//!   "services": [
+//!     {
+//!       "name": { "namespace": "urn:hello", "name": "HelloService" },
+//!       "documentation": null,
+//!       "ports": [
+//!         {
+//!           "name": "HelloPort",
+//!           "documentation": null,
+//!           "binding": 0,
+//!           "address": "http://localhost/hello",
+//!           "protocol": "soap11"
+//!         }
+//!       ]
+//!     }
+//!   ]
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use roxmltree::{ExpandedName, Node, NodeId};
+
+use crate::wsdl::{resolve_qualified, Result, WsComponentKind, WsErrorType};
+use crate::{
+    OperationKind, SoapStyle, SoapUse, SoapVersion, WsBinding, WsBindingMessage, WsDefinitions,
+    WsError, WsMessage, WsPortType, WsProtocol, WsdlSet,
+};
+
+/// An expanded name: a local name qualified by an optional namespace.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QName {
+    pub namespace: Option<String>,
+    pub name: String,
+}
+
+impl QName {
+    fn resolve(node: Node, attribute: &str, kind: WsComponentKind) -> Result<Option<Self>> {
+        node.attribute(attribute)
+            .map(|name| {
+                resolve_qualified(node, name, kind)
+                    .map(Self::from)
+                    .map_err(|e| WsError::new(node, e))
+            })
+            .transpose()
+    }
+
+    pub(crate) fn within(namespace: Option<&str>, name: &str) -> Self {
+        Self {
+            namespace: namespace.map(str::to_string),
+            name: name.to_string(),
+        }
+    }
+}
+
+impl From<ExpandedName<'_, '_>> for QName {
+    fn from(name: ExpandedName<'_, '_>) -> Self {
+        Self::within(name.namespace(), name.name())
+    }
+}
+
+impl std::fmt::Display for QName {
+    /// Format the name in Clark notation, such as `{urn:example}Name`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.namespace {
+            Some(ns) => write!(f, "{{{}}}{}", ns, self.name),
+            None => f.write_str(&self.name),
+        }
+    }
+}
+
+/// An owned copy of [WsDefinitions].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Definitions {
+    pub target_namespace: Option<String>,
+    pub documentation: Option<String>,
+    pub messages: Vec<Message>,
+    pub port_types: Vec<PortType>,
+    pub bindings: Vec<Binding>,
+    pub services: Vec<Service>,
+}
+
+/// An owned copy of a [WsMessage].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Message {
+    pub name: QName,
+    pub documentation: Option<String>,
+    pub parts: Vec<Part>,
+}
+
+/// An owned copy of a [WsMessagePart](crate::WsMessagePart). A part refers
+/// to either a schema element or a schema type.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Part {
+    pub name: String,
+    pub documentation: Option<String>,
+    pub element: Option<QName>,
+    pub type_name: Option<QName>,
+}
+
+/// An owned copy of a [WsPortType].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PortType {
+    pub name: QName,
+    pub documentation: Option<String>,
+    pub operations: Vec<Operation>,
+}
+
+/// An owned copy of a [WsPortOperation](crate::WsPortOperation).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Operation {
+    pub name: String,
+    pub documentation: Option<String>,
+    pub kind: OperationKind,
+    /// The index of the input message in [Definitions::messages].
+    pub input: Option<usize>,
+    /// The index of the output message in [Definitions::messages].
+    pub output: Option<usize>,
+    pub faults: Vec<Fault>,
+    pub parameter_order: Option<Vec<String>>,
+}
+
+/// An owned copy of a [WsOperationFault](crate::WsOperationFault).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fault {
+    pub name: String,
+    /// The index of the fault message in [Definitions::messages].
+    pub message: usize,
+}
+
+/// An owned copy of a [WsBinding].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Binding {
+    pub name: QName,
+    pub documentation: Option<String>,
+    /// The index of the bound port type in [Definitions::port_types].
+    pub port_type: usize,
+    pub soap: Option<SoapBinding>,
+    pub operations: Vec<BindingOperation>,
+}
+
+/// The details of a [SoapBinding](crate::SoapBinding).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoapBinding {
+    pub version: SoapVersion,
+    pub transport: String,
+    pub style: SoapStyle,
+}
+
+/// An owned copy of a [WsBindingOperation](crate::WsBindingOperation).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BindingOperation {
+    pub name: String,
+    pub documentation: Option<String>,
+    /// The index of the bound operation in the [PortType::operations] of
+    /// the binding's port type.
+    pub operation: usize,
+    pub soap_action: Option<String>,
+    /// The SOAP style of the operation, if it belongs to a SOAP binding.
+    pub style: Option<SoapStyle>,
+    pub input: Option<BindingMessage>,
+    pub output: Option<BindingMessage>,
+    pub faults: Vec<BindingFault>,
+}
+
+/// An owned copy of the input or output of a [BindingOperation].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BindingMessage {
+    pub name: Option<String>,
+    pub body: Option<SoapBody>,
+    pub headers: Vec<SoapHeader>,
+}
+
+/// The details of a [SoapBody](crate::SoapBody).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoapBody {
+    pub usage: Option<SoapUse>,
+    /// The names of the parts in the body. If unspecified, every part is.
+    pub parts: Option<Vec<String>>,
+    pub namespace: Option<String>,
+}
+
+/// The details of a [SoapHeader](crate::SoapHeader).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoapHeader {
+    /// The index of the header's message in [Definitions::messages].
+    pub message: usize,
+    pub part: String,
+    pub usage: Option<SoapUse>,
+    pub namespace: Option<String>,
+}
+
+/// An owned copy of a fault of a [BindingOperation].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BindingFault {
+    pub name: Option<String>,
+    pub usage: Option<SoapUse>,
+    pub namespace: Option<String>,
+}
+
+/// An owned copy of a [WsService](crate::WsService).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Service {
+    pub name: QName,
+    pub documentation: Option<String>,
+    pub ports: Vec<Port>,
+}
+
+/// An owned copy of a [WsServicePort](crate::WsServicePort).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Port {
+    pub name: String,
+    pub documentation: Option<String>,
+    /// The index of the port's binding in [Definitions::bindings].
+    pub binding: usize,
+    pub address: Option<String>,
+    pub protocol: Option<WsProtocol>,
+}
+
+// Collects the components to be copied, assigning each an index the first
+// time it is seen. Components referenced from another document of a set are
+// appended after those of the definitions being copied.
+#[derive(Default)]
+struct Builder<'a, 'input> {
+    messages: Vec<WsMessage<'a, 'input>>,
+    port_types: Vec<WsPortType<'a, 'input>>,
+    bindings: Vec<WsBinding<'a, 'input>>,
+    // The index of each component seen so far, by node.
+    message_indices: HashMap<NodeKey, usize>,
+    port_type_indices: HashMap<NodeKey, usize>,
+    binding_indices: HashMap<NodeKey, usize>,
+}
+
+// Node IDs are only unique within a document, so nodes of a set are told apart
+// by the address of their document's text as well.
+type NodeKey = (usize, NodeId);
+
+fn node_key(node: Node) -> NodeKey {
+    (node.document().input_text().as_ptr() as usize, node.id())
+}
+
+// Look up the index of a component, appending it if it wasn't seen before.
+fn insert<T>(
+    components: &mut Vec<T>,
+    indices: &mut HashMap<NodeKey, usize>,
+    node: Node,
+    component: T,
+) -> usize {
+    *indices.entry(node_key(node)).or_insert_with(|| {
+        components.push(component);
+        components.len() - 1
+    })
+}
+
+impl<'a, 'input> Builder<'a, 'input> {
+    fn message(&mut self, message: WsMessage<'a, 'input>) -> usize {
+        let node = message.0;
+        insert(&mut self.messages, &mut self.message_indices, node, message)
+    }
+
+    fn port_type(&mut self, port_type: WsPortType<'a, 'input>) -> usize {
+        let node = port_type.0;
+        insert(
+            &mut self.port_types,
+            &mut self.port_type_indices,
+            node,
+            port_type,
+        )
+    }
+
+    fn binding(&mut self, binding: WsBinding<'a, 'input>) -> usize {
+        let node = binding.0;
+        insert(&mut self.bindings, &mut self.binding_indices, node, binding)
+    }
+
+    fn binding_message(
+        &mut self,
+        message: Option<WsBindingMessage<'a, 'input>>,
+    ) -> Result<Option<BindingMessage>> {
+        let Some(message) = message else {
+            return Ok(None);
+        };
+
+        let body = message
+            .soap_body()?
+            .map(|body| {
+                Ok::<_, WsError>(SoapBody {
+                    usage: body.usage()?,
+                    parts: body
+                        .parts()
+                        .map(|p| p.into_iter().map(str::to_string).collect()),
+                    namespace: body.namespace().map(str::to_string),
+                })
+            })
+            .transpose()?;
+
+        let mut headers = Vec::new();
+        for header in message.soap_headers()? {
+            headers.push(SoapHeader {
+                message: self.message(header.message()?),
+                part: header.part()?.to_string(),
+                usage: header.usage()?,
+                namespace: header.namespace().map(str::to_string),
+            });
+        }
+
+        Ok(Some(BindingMessage {
+            name: message.name().map(str::to_string),
+            body,
+            headers,
+        }))
+    }
+
+    fn copy_service(&mut self, service: &crate::WsService<'a, 'input>) -> Result<Service> {
+        let mut ports = Vec::new();
+        for port in service.ports()? {
+            let address = port.address()?;
+
+            ports.push(Port {
+                name: port.name()?.to_string(),
+                documentation: port.documentation(),
+                binding: self.binding(port.binding()?),
+                address: address
+                    .as_ref()
+                    .map(|a| a.location().map(str::to_string))
+                    .transpose()?,
+                protocol: address.as_ref().map(|a| a.protocol()),
+            });
+        }
+
+        Ok(Service {
+            name: QName::within(target_namespace(service.0), service.name()?),
+            documentation: service.documentation(),
+            ports,
+        })
+    }
+
+    fn copy_binding(&mut self, binding: &WsBinding<'a, 'input>) -> Result<Binding> {
+        let port_type = binding.port_type()?;
+        let port_operations = port_type
+            .operations()?
+            .enumerate()
+            .map(|(index, o)| (node_key(o.0), index))
+            .collect::<HashMap<_, _>>();
+
+        let mut operations = Vec::new();
+        for operation in binding.operations()? {
+            let port_operation = operation.port_operation()?;
+            let soap = operation.soap_operation()?;
+
+            let mut faults = Vec::new();
+            for fault in operation.faults()? {
+                let soap = fault.soap_fault()?;
+                faults.push(BindingFault {
+                    name: fault.name().map(str::to_string),
+                    usage: soap.as_ref().map(|f| f.usage()).transpose()?.flatten(),
+                    namespace: soap.and_then(|f| f.namespace()).map(str::to_string),
+                });
+            }
+
+            let Some(&index) = port_operations.get(&node_key(port_operation.0)) else {
+                return Err(WsError::new(
+                    operation.0,
+                    WsErrorType::UnknownComponent {
+                        name: QName::within(target_namespace(port_type.0), port_operation.name()?),
+                        kind: WsComponentKind::Operation,
+                    },
+                ));
+            };
+
+            operations.push(BindingOperation {
+                name: operation.name()?.to_string(),
+                documentation: operation.documentation(),
+                operation: index,
+                soap_action: soap
+                    .as_ref()
+                    .and_then(|s| s.soap_action())
+                    .map(str::to_string),
+                style: soap.as_ref().map(|s| s.style()).transpose()?,
+                input: self.binding_message(operation.input()?)?,
+                output: self.binding_message(operation.output()?)?,
+                faults,
+            });
+        }
+
+        let soap = binding
+            .soap_binding()?
+            .map(|soap| {
+                Ok::<_, WsError>(SoapBinding {
+                    version: soap.version(),
+                    transport: soap.transport()?.to_string(),
+                    style: soap.style()?,
+                })
+            })
+            .transpose()?;
+
+        Ok(Binding {
+            name: QName::within(target_namespace(binding.0), binding.name()?),
+            documentation: binding.documentation(),
+            port_type: self.port_type(port_type),
+            soap,
+            operations,
+        })
+    }
+
+    fn copy_port_type(&mut self, port_type: &WsPortType<'a, 'input>) -> Result<PortType> {
+        let mut operations = Vec::new();
+        for operation in port_type.operations()? {
+            let mut faults = Vec::new();
+            for fault in operation.faults()? {
+                faults.push(Fault {
+                    name: fault.name()?.to_string(),
+                    message: self.message(fault.message()?),
+                });
+            }
+
+            operations.push(Operation {
+                name: operation.name()?.to_string(),
+                documentation: operation.documentation(),
+                kind: operation.kind()?,
+                input: operation.input()?.map(|m| self.message(m)),
+                output: operation.output()?.map(|m| self.message(m)),
+                faults,
+                parameter_order: operation
+                    .parameter_order()
+                    .map(|p| p.into_iter().map(str::to_string).collect()),
+            });
+        }
+
+        Ok(PortType {
+            name: QName::within(target_namespace(port_type.0), port_type.name()?),
+            documentation: port_type.documentation(),
+            operations,
+        })
+    }
+
+    fn copy_message(&self, message: &WsMessage<'a, 'input>) -> Result<Message> {
+        let mut parts = Vec::new();
+        for part in message.parts() {
+            parts.push(Part {
+                name: part.name()?.to_string(),
+                documentation: part.documentation(),
+                element: QName::resolve(part.0, "element", WsComponentKind::SchemaElement)?,
+                type_name: QName::resolve(part.0, "type", WsComponentKind::SchemaType)?,
+            });
+        }
+
+        Ok(Message {
+            name: QName::within(target_namespace(message.0), message.name()?),
+            documentation: message.documentation(),
+            parts,
+        })
+    }
+
+    // Copy every component of the given definitions, along with anything
+    // they refer to. Components are copied in dependency order, as copying
+    // one may discover others.
+    fn build(mut self, definitions: &[WsDefinitions<'a, 'input>]) -> Result<Definitions> {
+        let mut services = Vec::new();
+        for d in definitions {
+            for message in d.messages()? {
+                self.message(message);
+            }
+            for port_type in d.port_types()? {
+                self.port_type(port_type);
+            }
+            for binding in d.bindings()? {
+                self.binding(binding);
+            }
+            services.extend(d.services()?);
+        }
+
+        let services = services
+            .iter()
+            .map(|s| self.copy_service(s))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut bindings = Vec::new();
+        while let Some(binding) = self.bindings.get(bindings.len()).cloned() {
+            bindings.push(self.copy_binding(&binding)?);
+        }
+
+        let mut port_types = Vec::new();
+        while let Some(port_type) = self.port_types.get(port_types.len()).cloned() {
+            port_types.push(self.copy_port_type(&port_type)?);
+        }
+
+        let messages = self
+            .messages
+            .iter()
+            .map(|m| self.copy_message(m))
+            .collect::<Result<Vec<_>>>()?;
+
+        let root = definitions.first();
+        Ok(Definitions {
+            target_namespace: root.and_then(|d| d.target_namespace()).map(str::to_string),
+            documentation: root.and_then(|d| d.documentation()),
+            messages,
+            port_types,
+            bindings,
+            services,
+        })
+    }
+}
+
+// Retrieve the target namespace of the definitions enclosing a component.
+fn target_namespace<'a>(node: Node<'a, '_>) -> Option<&'a str> {
+    node.parent().and_then(|n| n.attribute("targetNamespace"))
+}
+
+impl Definitions {
+    /// Copy the given definitions, along with any components they refer to
+    /// in other documents of their [WsdlSet].
+    pub fn new(definitions: &WsDefinitions) -> Result<Self> {
+        Builder::default().build(std::slice::from_ref(definitions))
+    }
+
+    /// Copy the definitions of every document in a set into a single model.
+    /// The target namespace and documentation are those of the set's root.
+    pub fn from_set(set: &WsdlSet) -> Result<Self> {
+        Builder::default().build(&set.definitions().collect::<Vec<_>>())
+    }
+
+    /// Find a message by its expanded name.
+    pub fn message(&self, name: &QName) -> Option<&Message> {
+        self.messages.iter().find(|m| &m.name == name)
+    }
+
+    /// Find a port type by its expanded name.
+    pub fn port_type(&self, name: &QName) -> Option<&PortType> {
+        self.port_types.iter().find(|p| &p.name == name)
+    }
+
+    /// Find a binding by its expanded name.
+    pub fn binding(&self, name: &QName) -> Option<&Binding> {
+        self.bindings.iter().find(|b| &b.name == name)
+    }
+
+    /// Find a service by its expanded name.
+    pub fn service(&self, name: &QName) -> Option<&Service> {
+        self.services.iter().find(|s| &s.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MemoryResolver, WsdlSources};
+    use roxmltree::Document;
+
+    const HELLO_NS: &str = "http://learnwebservices.com/services/hello";
+
+    fn assert_owned<T: Send + Sync + Clone + 'static>() {}
+
+    #[test]
+    fn owned_types_are_send_sync_and_clone() {
+        assert_owned::<Definitions>();
+        assert_owned::<QName>();
+    }
+
+    #[test]
+    fn resolve_references_to_indices() {
+        let text = include_str!("../assets/hello/hello.wsdl");
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let definitions = Definitions::new(&definitions).unwrap();
+        let qname = |name: &str| QName::within(Some(HELLO_NS), name);
+
+        assert_eq!(definitions.target_namespace.as_deref(), Some(HELLO_NS));
+        assert_eq!(definitions.messages.len(), 2);
+        let message = definitions.message(&qname("SayHello")).unwrap();
+        assert_eq!(message.parts[0].element, Some(qname("SayHello")));
+        assert_eq!(message.parts[0].type_name, None);
+
+        let service = definitions.service(&qname("HelloEndpointService")).unwrap();
+        let port = &service.ports[0];
+        assert_eq!(port.name, "HelloEndpointPort");
+        assert_eq!(port.protocol, Some(WsProtocol::Soap11));
+        assert_eq!(
+            port.address.as_deref(),
+            Some("http://www.learnwebservices.com/services/hello")
+        );
+
+        let binding = &definitions.bindings[port.binding];
+        assert_eq!(binding.name, qname("HelloEndpointServiceSoapBinding"));
+        assert_eq!(
+            definitions.binding(&binding.name).map(|b| &b.name),
+            Some(&binding.name)
+        );
+        let soap = binding.soap.as_ref().unwrap();
+        assert_eq!(soap.version, SoapVersion::Soap11);
+        assert_eq!(soap.style, SoapStyle::Document);
+
+        let port_type = &definitions.port_types[binding.port_type];
+        assert_eq!(port_type.name, qname("HelloEndpoint"));
+        let bound = &binding.operations[0];
+        assert_eq!(bound.soap_action.as_deref(), Some(""));
+        assert_eq!(bound.style, Some(SoapStyle::Document));
+        let body = bound.input.as_ref().unwrap().body.as_ref().unwrap();
+        assert_eq!(body.usage, Some(SoapUse::Literal));
+
+        let operation = &port_type.operations[bound.operation];
+        assert_eq!(operation.name, "SayHello");
+        assert_eq!(operation.kind, OperationKind::RequestResponse);
+        let input = &definitions.messages[operation.input.unwrap()];
+        assert_eq!(input.name, qname("SayHello"));
+        let output = &definitions.messages[operation.output.unwrap()];
+        assert_eq!(output.name, qname("SayHelloResponse"));
+    }
+
+    #[test]
+    fn copy_each_component_of_a_set_once() {
+        let service = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+                xmlns:a="urn:a" xmlns:b="urn:b" targetNamespace="urn:a">
+              <import namespace="urn:b" location="b.wsdl"/>
+              <portType name="Port">
+                <operation name="First">
+                  <input message="b:Shared"/>
+                  <output message="b:Shared"/>
+                </operation>
+                <operation name="Second">
+                  <input message="b:Shared"/>
+                </operation>
+              </portType>
+              <binding name="Binding" type="b:Port"/>
+            </definitions>"#;
+        let other = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+                xmlns:b="urn:b" targetNamespace="urn:b">
+              <message name="Shared"/>
+              <message name="Unused"/>
+              <portType name="Port">
+                <operation name="Third"><input message="b:Shared"/></operation>
+              </portType>
+            </definitions>"#;
+
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("a.wsdl", service).insert("b.wsdl", other);
+        let sources = WsdlSources::load(&resolver, "a.wsdl").unwrap();
+        let set = WsdlSet::parse(&sources).unwrap();
+
+        // Only what the root refers to is copied from the other document.
+        let definitions = Definitions::new(&set.root().unwrap()).unwrap();
+        assert_eq!(definitions.target_namespace.as_deref(), Some("urn:a"));
+        assert_eq!(definitions.messages.len(), 1);
+        assert_eq!(definitions.port_types.len(), 2);
+        let shared = QName::within(Some("urn:b"), "Shared");
+        assert_eq!(definitions.messages[0].name, shared);
+        for operation in &definitions.port_types[0].operations {
+            assert_eq!(operation.input, Some(0));
+        }
+        let binding = &definitions.bindings[0];
+        let port_type = &definitions.port_types[binding.port_type];
+        assert_eq!(port_type.name, QName::within(Some("urn:b"), "Port"));
+
+        let definitions = Definitions::from_set(&set).unwrap();
+        assert_eq!(definitions.messages.len(), 2);
+        assert_eq!(definitions.port_types.len(), 2);
+        let names: Vec<_> = definitions.messages.iter().map(|m| &m.name.name).collect();
+        assert_eq!(names, ["Shared", "Unused"]);
+        for port_type in &definitions.port_types {
+            for operation in &port_type.operations {
+                assert_eq!(operation.input, Some(0));
+            }
+        }
+    }
+
+    #[test]
+    fn copy_definitions_without_target_namespace() {
+        // Unprefixed references name components in no namespace, since there
+        // is no default namespace either.
+        let text = r#"<wsdl:definitions xmlns:wsdl="http://schemas.xmlsoap.org/wsdl/">
+              <wsdl:message name="Request"/>
+              <wsdl:portType name="Port">
+                <wsdl:operation name="Echo"><wsdl:input message="Request"/></wsdl:operation>
+              </wsdl:portType>
+              <wsdl:binding name="Binding" type="Port">
+                <wsdl:operation name="Echo"><wsdl:input/></wsdl:operation>
+              </wsdl:binding>
+              <wsdl:service name="Service">
+                <wsdl:port name="Port" binding="Binding"/>
+              </wsdl:service>
+            </wsdl:definitions>"#;
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let definitions = Definitions::new(&definitions).unwrap();
+
+        assert_eq!(definitions.target_namespace, None);
+        let port_type = QName::within(None, "Port");
+        assert_eq!(
+            definitions.port_type(&port_type).unwrap().operations.len(),
+            1
+        );
+        let binding = definitions
+            .binding(&QName::within(None, "Binding"))
+            .unwrap();
+        assert_eq!(binding.operations[0].operation, 0);
+        assert_eq!(binding.name.to_string(), "Binding");
+
+        let service = definitions
+            .service(&QName::within(None, "Service"))
+            .unwrap();
+        assert_eq!(service.ports[0].binding, 0);
+        assert_eq!(service.ports[0].address, None);
+        assert_eq!(definitions.messages[0].name.to_string(), "Request");
+    }
+
+    // The service the JSON example of the module documentation describes.
+    #[cfg(feature = "serde")]
+    const MINIMAL: &str = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+            xmlns:soap="http://schemas.xmlsoap.org/wsdl/soap/"
+            xmlns:tns="urn:hello" targetNamespace="urn:hello">
+          <message name="SayHello">
+            <part name="parameters" element="tns:SayHello"/>
+          </message>
+          <portType name="Hello">
+            <operation name="SayHello"><input message="tns:SayHello"/></operation>
+          </portType>
+          <binding name="HelloSoap" type="tns:Hello">
+            <soap:binding style="document" transport="http://schemas.xmlsoap.org/soap/http"/>
+            <operation name="SayHello">
+              <soap:operation soapAction=""/>
+              <input><soap:body use="literal"/></input>
+            </operation>
+          </binding>
+          <service name="HelloService">
+            <port name="HelloPort" binding="tns:HelloSoap">
+              <soap:address location="http://localhost/hello"/>
+            </port>
+          </service>
+        </definitions>"#;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_as_documented() {
+        // Extract the example from the module documentation.
+        let source = include_str!("owned.rs");
+        let start = source.find("//! ```json\n").unwrap() + "//! ```json\n".len();
+        let end = start + source[start..].find("//! ```\n").unwrap();
+        let documented = source[start..end]
+            .lines()
+            .map(|l| l.trim_start_matches("//!"))
+            .collect::<String>();
+        let documented: serde_json::Value = serde_json::from_str(&documented).unwrap();
+
+        let document = Document::parse(MINIMAL).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let definitions = Definitions::new(&definitions).unwrap();
+        assert_eq!(serde_json::to_value(&definitions).unwrap(), documented);
+
+        let json = serde_json::to_string(&definitions).unwrap();
+        let copy: Definitions = serde_json::from_str(&json).unwrap();
+        assert_eq!(copy, definitions);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trip_through_json() {
+        let parse = |text| {
+            let document = Document::parse(text).unwrap();
+            let definitions = WsDefinitions::from_document(&document).unwrap();
+            Definitions::new(&definitions).unwrap()
+        };
+
+        for text in [
+            include_str!("../assets/hello/hello.wsdl"),
+            include_str!("../assets/weather/weather.wsdl"),
+        ] {
+            let definitions = parse(text);
+            let json = serde_json::to_string(&definitions).unwrap();
+            let copy: Definitions = serde_json::from_str(&json).unwrap();
+            assert_eq!(copy, definitions);
+        }
+
+        let definitions = parse(include_str!("../assets/weather/weather.wsdl"));
+        let value = serde_json::to_value(&definitions).unwrap();
+        assert_eq!(value["services"][0]["ports"][1]["protocol"], "soap12");
+        assert_eq!(value["services"][0]["ports"][2]["protocol"], "http");
+        let kind = &value["port_types"][0]["operations"][0]["kind"];
+        assert_eq!(kind, "request-response");
+    }
+}
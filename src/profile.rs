@@ -0,0 +1,657 @@
+use std::collections::HashSet;
+
+use roxmltree::Node;
+
+use crate::wsdl::{Result, WsErrorType};
+use crate::{
+    OperationKind, SoapBody, SoapStyle, SoapUse, SoapVersion, WsBindingMessage, WsDefinitions,
+    WsDiagnostic, WsError, WsMessage, WsMessagePart, WsSeverity,
+};
+
+// The only transport the profile allows, as defined in section 3.3 of the WSDL 1.1 specification.
+const SOAP_HTTP: &str = "http://schemas.xmlsoap.org/soap/http";
+
+/// A requirement of the WS-I Basic Profile on WSDL descriptions. The
+/// variants are named after the assertion IDs, which are shared by
+/// versions 1.1 and 1.2 of the profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BpAssertion {
+    /// A document-literal `soap:body` lists at most one part.
+    R2201,
+    /// An rpc-literal `soap:body` only refers to parts defined with `type`.
+    R2203,
+    /// A document-literal `soap:body` only refers to parts defined with `element`.
+    R2204,
+    /// A `soap:header`, `soap:headerfault` or `soap:fault` only refers to
+    /// parts defined with `element`.
+    R2205,
+    /// A document-literal `soap:body` without a `parts` attribute binds a
+    /// message with at most one part.
+    R2210,
+    /// Port types have no solicit-response or notification operations.
+    R2303,
+    /// The operations of a port type have distinct names.
+    R2304,
+    /// Bindings use the SOAP 1.1 binding of WSDL 1.1.
+    R2401,
+    /// The `soap:binding` specifies a transport.
+    R2701,
+    /// The transport of the `soap:binding` is HTTP.
+    R2702,
+    /// Every operation of a binding has the same style.
+    R2705,
+    /// Bodies, headers and faults are `literal`.
+    R2706,
+    /// Document-literal bodies, headers and faults have no `namespace`.
+    R2716,
+    /// Rpc-literal bodies have an absolute `namespace`.
+    R2717,
+    /// A binding has the same operations as its port type.
+    R2718,
+    /// Every `soap:fault` has a name.
+    R2721,
+    /// The name of a `soap:fault` matches the enclosing `wsdl:fault`.
+    R2754,
+    /// Requests for operations without a `soapAction`, or with an empty one,
+    /// carry an empty `SOAPAction` header. This is allowed, but makes it
+    /// impossible to dispatch on the header.
+    R2745,
+}
+
+impl BpAssertion {
+    /// Retrieve the assertion ID, such as `R2706`.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::R2201 => "R2201",
+            Self::R2203 => "R2203",
+            Self::R2204 => "R2204",
+            Self::R2205 => "R2205",
+            Self::R2210 => "R2210",
+            Self::R2303 => "R2303",
+            Self::R2304 => "R2304",
+            Self::R2401 => "R2401",
+            Self::R2701 => "R2701",
+            Self::R2702 => "R2702",
+            Self::R2705 => "R2705",
+            Self::R2706 => "R2706",
+            Self::R2716 => "R2716",
+            Self::R2717 => "R2717",
+            Self::R2718 => "R2718",
+            Self::R2721 => "R2721",
+            Self::R2754 => "R2754",
+            Self::R2745 => "R2745",
+        }
+    }
+
+    /// Summarize what the assertion requires.
+    pub fn requirement(&self) -> &'static str {
+        match self {
+            Self::R2201 => "a document-literal body must list at most one part",
+            Self::R2203 => "an rpc-literal body must only refer to parts defined with \"type\"",
+            Self::R2204 => {
+                "a document-literal body must only refer to parts defined with \"element\""
+            }
+            Self::R2205 => "headers and faults must only refer to parts defined with \"element\"",
+            Self::R2210 => {
+                "a document-literal body without \"parts\" must bind a message of at most one part"
+            }
+            Self::R2303 => "solicit-response and notification operations are not allowed",
+            Self::R2304 => "the operations of a port type must have distinct names",
+            Self::R2401 => "bindings must use the SOAP 1.1 binding",
+            Self::R2701 => "the SOAP binding must specify a transport",
+            Self::R2702 => "the SOAP binding must use the HTTP transport",
+            Self::R2705 => "the operations of a binding must all be rpc or all be document style",
+            Self::R2706 => "bodies, headers and faults must be literal",
+            Self::R2716 => {
+                "document-literal bodies, headers and faults must not specify a namespace"
+            }
+            Self::R2717 => "rpc-literal bodies must specify an absolute namespace",
+            Self::R2718 => "a binding must have the same operations as its port type",
+            Self::R2721 => "SOAP faults must be named",
+            Self::R2754 => "the name of a SOAP fault must match the name of its WSDL fault",
+            Self::R2745 => "operations without a soapAction are sent with an empty SOAPAction",
+        }
+    }
+
+    /// Retrieve how serious a violation is. Only [Self::R2745] is a warning,
+    /// as the profile allows it.
+    pub fn severity(&self) -> WsSeverity {
+        match self {
+            Self::R2745 => WsSeverity::Warning,
+            _ => WsSeverity::Error,
+        }
+    }
+}
+
+impl std::fmt::Display for BpAssertion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+// Collects the assertions a document violates.
+#[derive(Default)]
+struct Checker {
+    diagnostics: Vec<WsDiagnostic>,
+}
+
+impl Checker {
+    fn violation(&mut self, node: Node, assertion: BpAssertion) {
+        self.diagnostics.push(WsDiagnostic {
+            severity: assertion.severity(),
+            error: WsError::new(node, WsErrorType::ProfileViolation(assertion)),
+        });
+    }
+
+    // Check the attributes shared by bodies, headers and faults. Returns
+    // whether the node is literal, as the other assertions only apply then.
+    fn literal(
+        &mut self,
+        node: Node,
+        usage: Result<Option<SoapUse>>,
+        namespace: Option<&str>,
+        style: SoapStyle,
+    ) -> bool {
+        if matches!(usage, Ok(Some(SoapUse::Encoded))) {
+            self.violation(node, BpAssertion::R2706);
+            return false;
+        }
+
+        if style == SoapStyle::Document && namespace.is_some() {
+            self.violation(node, BpAssertion::R2716);
+        }
+
+        true
+    }
+
+    // Check that a header, header fault or fault refers to an element part.
+    fn element_part(&mut self, node: Node, part: Option<WsMessagePart>) {
+        if part.is_some_and(|p| !p.node().has_attribute("element")) {
+            self.violation(node, BpAssertion::R2205);
+        }
+    }
+
+    fn body(&mut self, body: &SoapBody, message: Option<&WsMessage>, style: SoapStyle) {
+        if !self.literal(body.node(), body.usage(), body.namespace(), style) {
+            return;
+        }
+
+        if style == SoapStyle::Rpc && !body.namespace().is_some_and(is_absolute_uri) {
+            self.violation(body.node(), BpAssertion::R2717);
+        }
+
+        let Some(message) = message else {
+            return;
+        };
+
+        let parts = match body.parts() {
+            Some(names) => {
+                if style == SoapStyle::Document && names.len() > 1 {
+                    self.violation(body.node(), BpAssertion::R2201);
+                }
+
+                message
+                    .parts()
+                    .filter(|p| p.name().is_ok_and(|n| names.contains(&n)))
+                    .collect::<Vec<_>>()
+            }
+            None => {
+                let parts = message.parts().collect::<Vec<_>>();
+                if style == SoapStyle::Document && parts.len() > 1 {
+                    self.violation(body.node(), BpAssertion::R2210);
+                }
+
+                parts
+            }
+        };
+
+        let (attribute, assertion) = match style {
+            SoapStyle::Document => ("element", BpAssertion::R2204),
+            SoapStyle::Rpc => ("type", BpAssertion::R2203),
+        };
+
+        if parts.iter().any(|p| !p.node().has_attribute(attribute)) {
+            self.violation(body.node(), assertion);
+        }
+    }
+
+    // Check the input or output of a binding operation, given the message of
+    // the corresponding port operation.
+    fn message(
+        &mut self,
+        binding: &WsBindingMessage,
+        message: Option<&WsMessage>,
+        style: SoapStyle,
+    ) {
+        if let Some(body) = binding.soap_body().ok().flatten() {
+            self.body(&body, message, style);
+        }
+
+        for header in binding.soap_headers().into_iter().flatten() {
+            self.literal(header.node(), header.usage(), header.namespace(), style);
+            self.element_part(header.node(), find_part(header.message(), header.part()));
+
+            for fault in header.header_faults() {
+                self.literal(fault.node(), fault.usage(), fault.namespace(), style);
+                self.element_part(fault.node(), find_part(fault.message(), fault.part()));
+            }
+        }
+    }
+}
+
+// Determine whether a URI is absolute, that is whether it starts with a scheme
+// such as `http:` or `urn:`.
+fn is_absolute_uri(uri: &str) -> bool {
+    let Some((scheme, _)) = uri.split_once(':') else {
+        return false;
+    };
+
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '.' | '-'))
+}
+
+// Find a part of a message, if both resolve.
+fn find_part<'a, 'input>(
+    message: Result<WsMessage<'a, 'input>>,
+    part: Result<&str>,
+) -> Option<WsMessagePart<'a, 'input>> {
+    let part = part.ok()?;
+    message.ok()?.parts().find(|p| p.name().ok() == Some(part))
+}
+
+impl<'a, 'input> WsDefinitions<'a, 'input> {
+    /// Check the definitions against the WS-I Basic Profile, and report
+    /// the assertions they violate in document order. Problems that make the
+    /// definitions invalid, such as dangling references, are left to
+    /// [Self::validate], and the parts of the definitions they affect are
+    /// skipped.
+    pub fn check_basic_profile(&self) -> Vec<WsDiagnostic> {
+        let mut c = Checker::default();
+
+        for port_type in self.port_types().into_iter().flatten() {
+            let mut names = HashSet::new();
+
+            for operation in port_type.operations().into_iter().flatten() {
+                if operation.name().is_ok_and(|n| !names.insert(n)) {
+                    c.violation(operation.node(), BpAssertion::R2304);
+                }
+
+                if matches!(
+                    operation.kind(),
+                    Ok(OperationKind::SolicitResponse | OperationKind::Notification)
+                ) {
+                    c.violation(operation.node(), BpAssertion::R2303);
+                }
+            }
+        }
+
+        for binding in self.bindings().into_iter().flatten() {
+            let soap = match binding.soap_binding() {
+                Ok(Some(soap)) if soap.version() == SoapVersion::Soap11 => soap,
+                Ok(Some(soap)) => {
+                    c.violation(soap.node(), BpAssertion::R2401);
+                    continue;
+                }
+                _ => {
+                    c.violation(binding.node(), BpAssertion::R2401);
+                    continue;
+                }
+            };
+
+            match soap.transport() {
+                Ok(SOAP_HTTP) => {}
+                Ok(_) => c.violation(soap.node(), BpAssertion::R2702),
+                Err(_) => c.violation(soap.node(), BpAssertion::R2701),
+            }
+
+            let port_type = binding.port_type().ok();
+            let mut bound = HashSet::new();
+            let mut styles = HashSet::new();
+
+            for operation in binding.operations().into_iter().flatten() {
+                if let Ok(name) = operation.name() {
+                    bound.insert(name);
+                }
+
+                let soap_operation = operation.soap_operation().ok().flatten();
+                let style = match &soap_operation {
+                    Some(o) => o.style(),
+                    None => soap.style(),
+                }
+                .unwrap_or(SoapStyle::Document);
+
+                // Report the first operation that departs from the others.
+                if styles.insert(style == SoapStyle::Rpc) && styles.len() == 2 {
+                    c.violation(operation.node(), BpAssertion::R2705);
+                }
+
+                match &soap_operation {
+                    Some(o) if o.soap_action().is_some_and(|a| !a.is_empty()) => {}
+                    Some(o) => c.violation(o.node(), BpAssertion::R2745),
+                    None => c.violation(operation.node(), BpAssertion::R2745),
+                }
+
+                // Operations missing from the port type can't be checked further.
+                if port_type.is_none() {
+                    continue;
+                }
+
+                let Ok(port_operation) = operation.port_operation() else {
+                    c.violation(operation.node(), BpAssertion::R2718);
+                    continue;
+                };
+
+                if let Some(input) = operation.input().ok().flatten() {
+                    let message = port_operation.input().ok().flatten();
+                    c.message(&input, message.as_ref(), style);
+                }
+
+                if let Some(output) = operation.output().ok().flatten() {
+                    let message = port_operation.output().ok().flatten();
+                    c.message(&output, message.as_ref(), style);
+                }
+
+                for fault in operation.faults().into_iter().flatten() {
+                    let Some(soap_fault) = fault.soap_fault().ok().flatten() else {
+                        continue;
+                    };
+
+                    match soap_fault.name() {
+                        Ok(name) if Some(name) != fault.name() => {
+                            c.violation(soap_fault.node(), BpAssertion::R2754)
+                        }
+                        Ok(_) => {}
+                        Err(_) => c.violation(soap_fault.node(), BpAssertion::R2721),
+                    }
+
+                    c.literal(
+                        soap_fault.node(),
+                        soap_fault.usage(),
+                        soap_fault.namespace(),
+                        style,
+                    );
+
+                    // The fault detail is made of the parts of the fault message.
+                    let message = port_operation
+                        .faults()
+                        .into_iter()
+                        .flatten()
+                        .find(|f| f.name().ok() == fault.name())
+                        .and_then(|f| f.message().ok());
+
+                    let part = message
+                        .iter()
+                        .flat_map(|m| m.parts())
+                        .find(|p| !p.node().has_attribute("element"));
+                    c.element_part(soap_fault.node(), part);
+                }
+            }
+
+            if port_type
+                .iter()
+                .filter_map(|p| p.operations().ok())
+                .flatten()
+                .any(|o| o.name().is_ok_and(|n| !bound.contains(n)))
+            {
+                c.violation(binding.node(), BpAssertion::R2718);
+            }
+        }
+
+        c.diagnostics.sort_by_key(|d| d.error.span().start);
+        c.diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A document with one rpc operation per given `soap:body`.
+    fn check(bodies: &[&str]) -> Vec<BpAssertion> {
+        let operations = bodies
+            .iter()
+            .enumerate()
+            .map(|(i, body)| {
+                format!(
+                    r#"<operation name="op{i}">
+                         <soap:operation soapAction="urn:op{i}"/>
+                         <input>{body}</input>
+                       </operation>"#
+                )
+            })
+            .collect::<String>();
+
+        let port_operations = (0..bodies.len())
+            .map(|i| format!(r#"<operation name="op{i}"><input message="tns:In"/></operation>"#))
+            .collect::<String>();
+
+        let text = format!(
+            r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+                   xmlns:soap="http://schemas.xmlsoap.org/wsdl/soap/"
+                   xmlns:xs="http://www.w3.org/2001/XMLSchema"
+                   xmlns:tns="urn:test" targetNamespace="urn:test">
+                 <message name="In"><part name="a" type="xs:string"/></message>
+                 <portType name="Port">{port_operations}</portType>
+                 <binding name="Binding" type="tns:Port">
+                   <soap:binding style="rpc" transport="http://schemas.xmlsoap.org/soap/http"/>
+                   {operations}
+                 </binding>
+               </definitions>"#
+        );
+
+        violations(&text)
+    }
+
+    // A document with the given port type and binding contents, and messages
+    // made of element parts (In and Two) or of a type part (Typed).
+    fn profile(port_type: &str, binding: &str) -> Vec<BpAssertion> {
+        violations(&format!(
+            r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+                   xmlns:soap="http://schemas.xmlsoap.org/wsdl/soap/"
+                   xmlns:soap12="http://schemas.xmlsoap.org/wsdl/soap12/"
+                   xmlns:xs="http://www.w3.org/2001/XMLSchema"
+                   xmlns:tns="urn:test" targetNamespace="urn:test">
+                 <message name="In"><part name="a" element="tns:a"/></message>
+                 <message name="Typed"><part name="a" type="xs:string"/></message>
+                 <message name="Two">
+                   <part name="a" element="tns:a"/>
+                   <part name="b" element="tns:b"/>
+                 </message>
+                 <portType name="Port">{port_type}</portType>
+                 <binding name="Binding" type="tns:Port">{binding}</binding>
+               </definitions>"#
+        ))
+    }
+
+    fn violations(text: &str) -> Vec<BpAssertion> {
+        let document = roxmltree::Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        definitions
+            .check_basic_profile()
+            .into_iter()
+            .map(|d| match d.error.error_type() {
+                WsErrorType::ProfileViolation(assertion) => *assertion,
+                other => panic!("unexpected error {other}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn report_each_assertion() {
+        const DOCUMENT: &str =
+            r#"<soap:binding style="document" transport="http://schemas.xmlsoap.org/soap/http"/>"#;
+        const RPC: &str =
+            r#"<soap:binding style="rpc" transport="http://schemas.xmlsoap.org/soap/http"/>"#;
+        const IN: &str = r#"<operation name="op"><input message="tns:In"/></operation>"#;
+        const TYPED: &str = r#"<operation name="op"><input message="tns:Typed"/></operation>"#;
+        const TWO: &str = r#"<operation name="op"><input message="tns:Two"/></operation>"#;
+
+        // A bound operation with the given soap:operation attributes and contents.
+        let op = |name: &str, attributes: &str, contents: &str| {
+            format!(
+                r#"<operation name="{name}">
+                     <soap:operation soapAction="urn:{name}" {attributes}/>
+                     {contents}
+                   </operation>"#
+            )
+        };
+        let body = |attributes: &str| {
+            op(
+                "op",
+                "",
+                &format!(r#"<input><soap:body use="literal" {attributes}/></input>"#),
+            )
+        };
+
+        let cases = [
+            (BpAssertion::R2201, TWO, DOCUMENT, body(r#"parts="a b""#)),
+            (BpAssertion::R2203, IN, RPC, body(r#"namespace="urn:test""#)),
+            (BpAssertion::R2204, TYPED, DOCUMENT, body("")),
+            (
+                BpAssertion::R2205,
+                IN,
+                DOCUMENT,
+                op(
+                    "op",
+                    "",
+                    r#"<input>
+                         <soap:body use="literal"/>
+                         <soap:header message="tns:Typed" part="a" use="literal"/>
+                       </input>"#,
+                ),
+            ),
+            (BpAssertion::R2210, TWO, DOCUMENT, body("")),
+            (
+                BpAssertion::R2303,
+                r#"<operation name="op"><output message="tns:In"/></operation>"#,
+                DOCUMENT,
+                op("op", "", r#"<output><soap:body use="literal"/></output>"#),
+            ),
+            (
+                BpAssertion::R2304,
+                r#"<operation name="op"><input message="tns:In"/></operation>
+                   <operation name="op"><input message="tns:In"/></operation>"#,
+                DOCUMENT,
+                body(""),
+            ),
+            (
+                BpAssertion::R2401,
+                IN,
+                r#"<soap12:binding style="document" transport="http://schemas.xmlsoap.org/soap/http"/>"#,
+                String::new(),
+            ),
+            (
+                BpAssertion::R2701,
+                IN,
+                r#"<soap:binding style="document"/>"#,
+                body(""),
+            ),
+            (
+                BpAssertion::R2702,
+                IN,
+                r#"<soap:binding style="document" transport="http://example.com/smtp"/>"#,
+                body(""),
+            ),
+            (
+                BpAssertion::R2705,
+                r#"<operation name="op"><input message="tns:In"/></operation>
+                   <operation name="other"><input message="tns:Typed"/></operation>"#,
+                DOCUMENT,
+                body("")
+                    + &op(
+                        "other",
+                        r#"style="rpc""#,
+                        r#"<input><soap:body use="literal" namespace="urn:test"/></input>"#,
+                    ),
+            ),
+            (
+                BpAssertion::R2706,
+                IN,
+                DOCUMENT,
+                op("op", "", r#"<input><soap:body use="encoded"/></input>"#),
+            ),
+            (
+                BpAssertion::R2716,
+                IN,
+                DOCUMENT,
+                body(r#"namespace="urn:test""#),
+            ),
+            (BpAssertion::R2717, TYPED, RPC, body("")),
+            (
+                // Reported once, however many operations are missing.
+                BpAssertion::R2718,
+                r#"<operation name="op"><input message="tns:In"/></operation>
+                   <operation name="second"><input message="tns:In"/></operation>
+                   <operation name="third"><input message="tns:In"/></operation>"#,
+                DOCUMENT,
+                body(""),
+            ),
+            (
+                BpAssertion::R2721,
+                r#"<operation name="op">
+                     <input message="tns:In"/>
+                     <fault name="Failed" message="tns:In"/>
+                   </operation>"#,
+                DOCUMENT,
+                op(
+                    "op",
+                    "",
+                    r#"<input><soap:body use="literal"/></input>
+                       <fault name="Failed"><soap:fault use="literal"/></fault>"#,
+                ),
+            ),
+            (
+                BpAssertion::R2754,
+                r#"<operation name="op">
+                     <input message="tns:In"/>
+                     <fault name="Failed" message="tns:In"/>
+                   </operation>"#,
+                DOCUMENT,
+                op(
+                    "op",
+                    "",
+                    r#"<input><soap:body use="literal"/></input>
+                       <fault name="Failed"><soap:fault name="Other" use="literal"/></fault>"#,
+                ),
+            ),
+            (
+                BpAssertion::R2745,
+                IN,
+                DOCUMENT,
+                r#"<operation name="op">
+                     <soap:operation soapAction=""/>
+                     <input><soap:body use="literal"/></input>
+                   </operation>"#
+                    .to_string(),
+            ),
+        ];
+
+        for (assertion, port_type, soap_binding, operations) in cases {
+            let violations = profile(port_type, &format!("{soap_binding}{operations}"));
+            assert_eq!(violations, [assertion], "{assertion}");
+        }
+
+        assert_eq!(profile(IN, &format!("{DOCUMENT}{}", body(""))), []);
+    }
+
+    #[test]
+    fn encoded_body_reports_r2706_only() {
+        let violations = check(&[r#"<soap:body use="encoded"/>"#]);
+        assert_eq!(violations, [BpAssertion::R2706]);
+    }
+
+    #[test]
+    fn rpc_namespace_must_be_absolute() {
+        let violations = check(&[
+            r#"<soap:body use="literal" namespace="urn:test"/>"#,
+            r#"<soap:body use="literal" namespace="http://example.com/"/>"#,
+            r#"<soap:body use="literal" namespace="x-my+ns.v1:a"/>"#,
+            r#"<soap:body use="literal"/>"#,
+            r#"<soap:body use="literal" namespace="1abc:test"/>"#,
+            r#"<soap:body use="literal" namespace="my ns:test"/>"#,
+            r#"<soap:body use="literal" namespace=":test"/>"#,
+        ]);
+        assert_eq!(violations, [BpAssertion::R2717; 4]);
+    }
+}
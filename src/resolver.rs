@@ -0,0 +1,649 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use roxmltree::{Document, Node};
+
+/// The contents of an external document, along with the URI it was loaded from.
+#[derive(Debug, Clone)]
+pub struct WsSource {
+    /// The URI of the document. Relative references made by the document
+    /// are resolved against this.
+    pub uri: String,
+    /// The text of the document.
+    pub text: String,
+}
+
+/// Loads the external documents referenced by a WSDL, such as the
+/// `location` of a `wsdl:import` or the `schemaLocation` of an `xsd:import`.
+pub trait WsResolver {
+    /// Load the document referenced by `location`. Relative locations are
+    /// interpreted against `base`, the URI of the referring document.
+    fn resolve(&self, base: Option<&str>, location: &str) -> std::io::Result<WsSource>;
+}
+
+impl<R: WsResolver + ?Sized> WsResolver for &R {
+    fn resolve(&self, base: Option<&str>, location: &str) -> std::io::Result<WsSource> {
+        (**self).resolve(base, location)
+    }
+}
+
+impl<R: WsResolver + ?Sized> WsResolver for Box<R> {
+    fn resolve(&self, base: Option<&str>, location: &str) -> std::io::Result<WsSource> {
+        (**self).resolve(base, location)
+    }
+}
+
+/// Join a possibly-relative `location` onto the `base` URI it was referenced
+/// from, following section 5.2 of RFC 3986. An absolute path keeps the scheme
+/// and authority of the base, such as `http://host`.
+pub(crate) fn join_uri(base: Option<&str>, location: &str) -> String {
+    let base = match base {
+        Some(base) if !location.contains("://") => base,
+        _ => return location.to_string(),
+    };
+
+    // Split the base into its scheme and authority, and its path.
+    let authority = match base.find("://") {
+        Some(i) => base[i + 3..].find('/').map_or(base.len(), |j| i + 3 + j),
+        None => 0,
+    };
+    let (prefix, base_path) = base.split_at(authority);
+
+    let path = if location.starts_with('/') {
+        location.to_string()
+    } else {
+        let dir = match base_path.rfind('/') {
+            Some(i) => &base_path[..=i],
+            None if !prefix.is_empty() => "/",
+            None => "",
+        };
+
+        format!("{}{}", dir, location)
+    };
+
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "." => {}
+            ".." if segments.last().is_some_and(|s| *s != ".." && !s.is_empty()) => {
+                segments.pop();
+            }
+            // There is nothing above the root of an absolute path.
+            ".." if segments == [""] => {}
+            _ => segments.push(segment),
+        }
+    }
+
+    format!("{}{}", prefix, segments.join("/"))
+}
+
+/// Decode the `%XX` escapes of a URI, such as the `%20` in
+/// `User%20Management%20Lookup_criteria.xsd`. Malformed escapes are kept verbatim.
+pub(crate) fn percent_decode(uri: &str) -> String {
+    let bytes = uri.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let escape = bytes
+            .get(i + 1..i + 3)
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match escape {
+            Some(b) => {
+                decoded.push(b);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8(decoded).unwrap_or_else(|_| uri.to_string())
+}
+
+/// Escape every character of `value` outside the URI unreserved set as `%XX`.
+pub(crate) fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for b in value.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(b as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", b)),
+        }
+    }
+
+    encoded
+}
+
+/// Compute the URI a `location` referenced from `base` resolves to.
+/// All built-in resolvers identify documents by this URI.
+pub(crate) fn resolve_uri(base: Option<&str>, location: &str) -> String {
+    percent_decode(&join_uri(base, location))
+}
+
+/// Serves documents from an in-memory map keyed by URI.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryResolver(HashMap<String, String>);
+
+impl MemoryResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the text of the document at `uri`.
+    pub fn insert(&mut self, uri: impl Into<String>, text: impl Into<String>) -> &mut Self {
+        self.0.insert(percent_decode(&uri.into()), text.into());
+        self
+    }
+}
+
+impl WsResolver for MemoryResolver {
+    fn resolve(&self, base: Option<&str>, location: &str) -> std::io::Result<WsSource> {
+        let uri = resolve_uri(base, location);
+        match self.0.get(&uri) {
+            Some(text) => Ok(WsSource {
+                uri,
+                text: text.clone(),
+            }),
+            None => Err(Error::new(
+                ErrorKind::NotFound,
+                format!("no document registered for \"{}\"", uri),
+            )),
+        }
+    }
+}
+
+/// Serves documents from the local filesystem. Relative locations are
+/// resolved against the directory of the referring document, and `file:`
+/// URIs are mapped to paths. Any other scheme is refused, so loading
+/// never touches the network. A rooted resolver also refuses absolute paths
+/// and paths that lead out of its root.
+#[derive(Debug, Clone, Default)]
+pub struct FileResolver(Option<PathBuf>);
+
+impl FileResolver {
+    /// Resolve paths relative to the current directory.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolve relative paths within the given directory.
+    pub fn rooted(root: impl Into<PathBuf>) -> Self {
+        Self(Some(root.into()))
+    }
+}
+
+impl WsResolver for FileResolver {
+    fn resolve(&self, base: Option<&str>, location: &str) -> std::io::Result<WsSource> {
+        let uri = resolve_uri(base, location);
+        let path = match uri.split_once("://") {
+            Some(("file", path)) => path,
+            Some((scheme, _)) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    format!("refusing to load \"{}\" over {}", uri, scheme),
+                ))
+            }
+            None => &uri,
+        };
+
+        let text = match &self.0 {
+            Some(root) => {
+                let escapes = || {
+                    Error::new(
+                        ErrorKind::PermissionDenied,
+                        format!("refusing to load \"{}\" from outside the root", uri),
+                    )
+                };
+
+                if Path::new(path).has_root() {
+                    return Err(escapes());
+                }
+
+                // Symbolic links and `..` segments are only followed within the root.
+                let file = root.join(path).canonicalize()?;
+                if !file.starts_with(root.canonicalize()?) {
+                    return Err(escapes());
+                }
+
+                std::fs::read_to_string(file)?
+            }
+            None => std::fs::read_to_string(path)?,
+        };
+
+        Ok(WsSource { uri, text })
+    }
+}
+
+const CATALOG_NS: &str = "urn:oasis:names:tc:entity:xmlns:xml:catalog";
+
+/// A single mapping of an [OASIS XML Catalog](https://www.oasis-open.org/committees/download.php/14809/xml-catalogs.html).
+#[derive(Debug, Clone)]
+enum CatalogEntry {
+    /// `uri` and `system`: replace one URI with another.
+    Exact(String, String),
+    /// `rewriteURI` and `rewriteSystem`: replace the start of a URI.
+    Rewrite(String, String),
+    /// `uriSuffix` and `systemSuffix`: replace any URI with the given ending.
+    Suffix(String, String),
+}
+
+/// Rewrites URIs according to OASIS XML Catalog files before handing them
+/// to another resolver. This lets remote `location`s be served from a local
+/// copy. URIs that are not mapped by the catalog are passed through as is.
+#[derive(Debug, Clone)]
+pub struct CatalogResolver<R> {
+    inner: R,
+    entries: Vec<CatalogEntry>,
+}
+
+impl<R: WsResolver> CatalogResolver<R> {
+    /// Create a resolver with no catalog entries.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Create a resolver from the catalog file at `location`, which is loaded
+    /// (along with any `nextCatalog`) through the inner resolver.
+    pub fn load(inner: R, location: &str) -> std::io::Result<Self> {
+        let mut resolver = Self::new(inner);
+        resolver.add_catalog(location)?;
+        Ok(resolver)
+    }
+
+    /// Add the entries of the catalog file at `location`. Entries that were
+    /// added earlier take precedence over these.
+    pub fn add_catalog(&mut self, location: &str) -> std::io::Result<&mut Self> {
+        let mut visited = Vec::new();
+        let mut pending = self.read_catalog(location, &mut visited)?;
+        pending.reverse();
+
+        // Locations of a `nextCatalog` are already joined onto the referring
+        // catalog. One that cannot be loaded is skipped, as OASIS requires.
+        while let Some(location) = pending.pop() {
+            if let Ok(next) = self.read_catalog(&location, &mut visited) {
+                // Depth-first, in document order.
+                pending.extend(next.into_iter().rev());
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Add the entries of a single catalog file, returning the locations of
+    /// its `nextCatalog` entries. A catalog that was already read adds nothing.
+    fn read_catalog(
+        &mut self,
+        location: &str,
+        visited: &mut Vec<String>,
+    ) -> std::io::Result<Vec<String>> {
+        let source = self.inner.resolve(None, location)?;
+        if visited.contains(&source.uri) {
+            return Ok(Vec::new());
+        }
+
+        let document =
+            Document::parse(&source.text).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        let root = document.root_element();
+        if !root.has_tag_name((CATALOG_NS, "catalog")) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("\"{}\" is not an XML catalog", source.uri),
+            ));
+        }
+
+        let mut next = Vec::new();
+        self.read_entries(root, &source.uri, &mut next);
+        visited.push(source.uri);
+        Ok(next)
+    }
+
+    /// Map the URI `from` to the URI `to`.
+    pub fn add_uri(&mut self, from: &str, to: impl Into<String>) -> &mut Self {
+        self.entries
+            .push(CatalogEntry::Exact(percent_decode(from), to.into()));
+        self
+    }
+
+    /// Map every URI that starts with `prefix` onto `replacement`.
+    pub fn add_rewrite(&mut self, prefix: &str, replacement: impl Into<String>) -> &mut Self {
+        self.entries.push(CatalogEntry::Rewrite(
+            percent_decode(prefix),
+            replacement.into(),
+        ));
+        self
+    }
+
+    fn read_entries(&mut self, node: Node, base: &str, next: &mut Vec<String>) {
+        let base = child_base(node, base);
+
+        for child in node.children().filter(|n| n.is_element()) {
+            if child.tag_name().namespace() != Some(CATALOG_NS) {
+                continue;
+            }
+
+            let entry = |from: &str, to: &str| -> Option<(String, String)> {
+                let target = child
+                    .attribute(to)
+                    .map(|t| resolve_uri(Some(&child_base(child, &base)), t))?;
+                Some((percent_decode(child.attribute(from)?), target))
+            };
+
+            let parsed = match child.tag_name().name() {
+                "uri" => entry("name", "uri").map(|(f, t)| CatalogEntry::Exact(f, t)),
+                "system" => entry("systemId", "uri").map(|(f, t)| CatalogEntry::Exact(f, t)),
+                "rewriteURI" => entry("uriStartString", "rewritePrefix")
+                    .map(|(f, t)| CatalogEntry::Rewrite(f, t)),
+                "rewriteSystem" => entry("systemIdStartString", "rewritePrefix")
+                    .map(|(f, t)| CatalogEntry::Rewrite(f, t)),
+                "uriSuffix" => entry("uriSuffix", "uri").map(|(f, t)| CatalogEntry::Suffix(f, t)),
+                "systemSuffix" => {
+                    entry("systemIdSuffix", "uri").map(|(f, t)| CatalogEntry::Suffix(f, t))
+                }
+                "group" => {
+                    self.read_entries(child, &base, next);
+                    None
+                }
+                "nextCatalog" => {
+                    if let Some(catalog) = child.attribute("catalog") {
+                        next.push(join_uri(Some(&child_base(child, &base)), catalog));
+                    }
+                    None
+                }
+                _ => None,
+            };
+
+            if let Some(entry) = parsed {
+                self.entries.push(entry);
+            }
+        }
+    }
+
+    /// Map `uri` to a replacement through the catalog entries. Exact matches
+    /// win over the longest matching rewrite, which wins over the longest
+    /// matching suffix. Among equally good matches, the earliest entry wins.
+    pub fn lookup(&self, uri: &str) -> Option<String> {
+        let uri = percent_decode(uri);
+
+        let exact = self.entries.iter().find_map(|e| match e {
+            CatalogEntry::Exact(from, to) if *from == uri => Some(to.clone()),
+            _ => None,
+        });
+
+        let rewrite = || {
+            self.entries
+                .iter()
+                .filter_map(|e| match e {
+                    CatalogEntry::Rewrite(from, to) => Some((from, to)),
+                    _ => None,
+                })
+                .filter(|(from, _)| uri.starts_with(from.as_str()))
+                .min_by_key(|(from, _)| Reverse(from.len()))
+                .map(|(from, to)| format!("{}{}", to, &uri[from.len()..]))
+        };
+
+        let suffix = || {
+            self.entries
+                .iter()
+                .filter_map(|e| match e {
+                    CatalogEntry::Suffix(from, to) => Some((from, to)),
+                    _ => None,
+                })
+                .filter(|(from, _)| uri.ends_with(from.as_str()))
+                .min_by_key(|(from, _)| Reverse(from.len()))
+                .map(|(_, to)| to.clone())
+        };
+
+        exact.or_else(rewrite).or_else(suffix)
+    }
+}
+
+// Apply the `xml:base` of a catalog entry, if any.
+fn child_base(node: Node, base: &str) -> String {
+    match node.attribute(("http://www.w3.org/XML/1998/namespace", "base")) {
+        Some(b) => join_uri(Some(base), b),
+        None => base.to_string(),
+    }
+}
+
+impl<R: WsResolver> WsResolver for CatalogResolver<R> {
+    fn resolve(&self, base: Option<&str>, location: &str) -> std::io::Result<WsSource> {
+        // Catalogs are matched against both the absolute and the literal reference.
+        let mapped = self
+            .lookup(&join_uri(base, location))
+            .or_else(|| self.lookup(location));
+
+        match mapped {
+            Some(uri) => self.inner.resolve(None, &uri),
+            None => self.inner.resolve(base, location),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn catalog(entries: &str) -> String {
+        format!(
+            r#"<catalog xmlns="urn:oasis:names:tc:entity:xmlns:xml:catalog">{}</catalog>"#,
+            entries
+        )
+    }
+
+    #[test]
+    fn join_relative_locations() {
+        let base = Some("http://example.com/wsdl/service.wsdl");
+        assert_eq!(
+            join_uri(base, "types.xsd"),
+            "http://example.com/wsdl/types.xsd"
+        );
+        assert_eq!(
+            join_uri(base, "./common/../types.xsd"),
+            "http://example.com/wsdl/types.xsd"
+        );
+        assert_eq!(
+            join_uri(base, "../schema/types.xsd"),
+            "http://example.com/schema/types.xsd"
+        );
+        assert_eq!(
+            join_uri(base, "/abs/types.xsd"),
+            "http://example.com/abs/types.xsd"
+        );
+        assert_eq!(
+            join_uri(base, "/../types.xsd"),
+            "http://example.com/types.xsd"
+        );
+        assert_eq!(
+            join_uri(Some("http://example.com"), "a.xsd"),
+            "http://example.com/a.xsd"
+        );
+        assert_eq!(
+            join_uri(Some("file:///a/b.wsdl"), "/x.xsd"),
+            "file:///x.xsd"
+        );
+        assert_eq!(join_uri(Some("a/b.wsdl"), "../../c.xsd"), "../c.xsd");
+        assert_eq!(join_uri(Some("a/b.wsdl"), "/c.xsd"), "/c.xsd");
+        assert_eq!(join_uri(Some("service.wsdl"), "types.xsd"), "types.xsd");
+        assert_eq!(join_uri(base, "file:///x.xsd"), "file:///x.xsd");
+        assert_eq!(join_uri(None, "types.xsd"), "types.xsd");
+    }
+
+    #[test]
+    fn decode_percent_escapes() {
+        assert_eq!(
+            percent_decode("User%20Management%20Lookup_criteria.xsd"),
+            "User Management Lookup_criteria.xsd"
+        );
+        assert_eq!(percent_decode("%C3%A9t%C3%A9"), "été");
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz%4"), "%zz%4");
+        assert_eq!(resolve_uri(Some("dir/a.wsdl"), "b%20c.xsd"), "dir/b c.xsd");
+        assert_eq!(percent_encode("a b/é~"), "a%20b%2F%C3%A9~");
+        assert_eq!(percent_decode(&percent_encode("a b/é")), "a b/é");
+    }
+
+    #[test]
+    fn memory_resolver_joins_and_decodes() {
+        let mut memory = MemoryResolver::new();
+        memory.insert("dir/b%20c.xsd", "<schema/>");
+
+        let source = memory.resolve(Some("dir/a.wsdl"), "b c.xsd").unwrap();
+        assert_eq!(source.uri, "dir/b c.xsd");
+        assert_eq!(source.text, "<schema/>");
+
+        let error = memory.resolve(None, "missing.xsd").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn catalog_precedence() {
+        let mut resolver = CatalogResolver::new(MemoryResolver::new());
+        resolver
+            .add_uri("http://example.com/a/b.xsd", "exact.xsd")
+            .add_rewrite("http://example.com/", "short/")
+            .add_rewrite("http://example.com/a/", "long/")
+            .add_rewrite("http://example.com/a/", "later/");
+        resolver
+            .entries
+            .push(CatalogEntry::Suffix("b.xsd".into(), "suffix.xsd".into()));
+        resolver
+            .entries
+            .push(CatalogEntry::Suffix("c.xsd".into(), "first.xsd".into()));
+        resolver
+            .entries
+            .push(CatalogEntry::Suffix("c.xsd".into(), "second.xsd".into()));
+
+        let lookup = |uri| resolver.lookup(uri);
+        assert_eq!(
+            lookup("http://example.com/a/b.xsd").as_deref(),
+            Some("exact.xsd")
+        );
+        assert_eq!(
+            lookup("http://example.com/a/c.xsd").as_deref(),
+            Some("long/c.xsd")
+        );
+        assert_eq!(
+            lookup("http://example.com/d.xsd").as_deref(),
+            Some("short/d.xsd")
+        );
+        assert_eq!(lookup("other/b.xsd").as_deref(), Some("suffix.xsd"));
+        assert_eq!(lookup("other/c.xsd").as_deref(), Some("first.xsd"));
+        assert_eq!(lookup("other/d.xsd"), None);
+    }
+
+    #[test]
+    fn catalog_next_catalog_cycle() {
+        let mut memory = MemoryResolver::new();
+        memory.insert(
+            "catalogs/a.xml",
+            catalog(
+                r#"<uri name="urn:a" uri="a.xsd"/>
+                   <nextCatalog catalog="b.xml"/>"#,
+            ),
+        );
+        memory.insert(
+            "catalogs/b.xml",
+            catalog(
+                r#"<uri name="urn:a" uri="shadowed.xsd"/>
+                   <uri name="urn:b" uri="b.xsd"/>
+                   <nextCatalog catalog="a.xml"/>"#,
+            ),
+        );
+
+        let resolver = CatalogResolver::load(memory, "catalogs/a.xml").unwrap();
+        assert_eq!(resolver.entries.len(), 3);
+        assert_eq!(resolver.lookup("urn:a").as_deref(), Some("catalogs/a.xsd"));
+        assert_eq!(resolver.lookup("urn:b").as_deref(), Some("catalogs/b.xsd"));
+    }
+
+    #[test]
+    fn catalog_skip_unloadable_next_catalog() {
+        let mut memory = MemoryResolver::new();
+        memory.insert(
+            "a.xml",
+            catalog(
+                r#"<nextCatalog catalog="missing.xml"/>
+                   <nextCatalog catalog="malformed.xml"/>
+                   <nextCatalog catalog="b.xml"/>"#,
+            ),
+        );
+        memory.insert("malformed.xml", "<catalog");
+        memory.insert("b.xml", catalog(r#"<uri name="urn:b" uri="b.xsd"/>"#));
+
+        let resolver = CatalogResolver::load(memory.clone(), "a.xml").unwrap();
+        assert_eq!(resolver.lookup("urn:b").as_deref(), Some("b.xsd"));
+
+        // The catalog that was asked for must still load.
+        assert!(CatalogResolver::load(memory.clone(), "missing.xml").is_err());
+        assert!(CatalogResolver::load(memory, "malformed.xml").is_err());
+    }
+
+    #[test]
+    fn catalog_xml_base() {
+        let mut memory = MemoryResolver::new();
+        memory.insert(
+            "catalog.xml",
+            catalog(
+                r#"<group xml:base="local/">
+                     <uri name="urn:a" uri="a.xsd"/>
+                     <uri name="urn:b" uri="b.xsd" xml:base="../other/"/>
+                   </group>
+                   <rewriteURI uriStartString="http://example.com/" rewritePrefix="mirror/"/>"#,
+            ),
+        );
+        memory.insert("local/a.xsd", "<a/>");
+
+        let resolver = CatalogResolver::load(memory, "catalog.xml").unwrap();
+        assert_eq!(resolver.lookup("urn:a").as_deref(), Some("local/a.xsd"));
+        assert_eq!(resolver.lookup("urn:b").as_deref(), Some("other/b.xsd"));
+        assert_eq!(
+            resolver.lookup("http://example.com/x.xsd").as_deref(),
+            Some("mirror/x.xsd")
+        );
+
+        let source = resolver.resolve(Some("service.wsdl"), "urn:a").unwrap();
+        assert_eq!(source.uri, "local/a.xsd");
+    }
+
+    #[test]
+    fn file_resolver_stays_within_root() {
+        let dir = std::env::temp_dir().join(format!("wsdl-resolver-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("root/sub")).unwrap();
+        std::fs::write(dir.join("root/sub/a.xsd"), "<a/>").unwrap();
+        std::fs::write(dir.join("outside.xsd"), "<outside/>").unwrap();
+
+        let resolver = FileResolver::rooted(dir.join("root"));
+        let source = resolver.resolve(Some("sub/service.wsdl"), "a.xsd").unwrap();
+        assert_eq!(source.text, "<a/>");
+        assert!(resolver.resolve(Some("sub/b.wsdl"), "../sub/a.xsd").is_ok());
+
+        let outside = dir.join("outside.xsd");
+        let refused = [
+            resolver.resolve(Some("sub/service.wsdl"), "../../outside.xsd"),
+            resolver.resolve(None, outside.to_str().unwrap()),
+            resolver.resolve(None, &format!("file://{}", outside.display())),
+        ];
+        for result in refused {
+            assert_eq!(result.unwrap_err().kind(), ErrorKind::PermissionDenied);
+        }
+
+        let error = resolver
+            .resolve(None, "http://example.com/a.xsd")
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Unsupported);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
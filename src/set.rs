@@ -0,0 +1,322 @@
+use std::collections::HashMap;
+
+use roxmltree::{Document, Node};
+use thiserror::Error;
+
+use crate::resolver::resolve_uri;
+use crate::wsdl::Scope;
+use crate::xsd::Schema;
+use crate::{WsDefinitions, WsError, WsLocation, WsResolver, WsSource};
+
+#[derive(Error, Debug)]
+pub enum WsLoadError {
+    #[error("failed to load \"{location}\": {source}")]
+    Io {
+        location: String,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to parse \"{uri}\": {source}")]
+    Parse {
+        uri: String,
+        #[source]
+        source: roxmltree::Error,
+    },
+}
+
+/// The texts of a set of WSDL documents, as gathered by following their
+/// `wsdl:import` statements and the `schemaLocation` of the `xs:import`,
+/// `xs:include` and `xs:redefine` statements of their schemas. Parse these
+/// into a [WsdlSet] to traverse them.
+#[derive(Debug, Clone, Default)]
+pub struct WsdlSources {
+    sources: Vec<WsSource>,
+    // The URI each followed reference was loaded from, by the URI of the
+    // referring document and the location it gave.
+    references: HashMap<(String, String), String>,
+}
+
+impl WsdlSources {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the document at `location` along with every document it
+    /// (transitively) imports.
+    pub fn load<R: WsResolver>(resolver: R, location: &str) -> Result<Self, WsLoadError> {
+        let mut sources = Self::new();
+        sources.add_location(resolver, location)?;
+        Ok(sources)
+    }
+
+    /// Add the document at `location` along with every document it
+    /// (transitively) imports. Documents that are already part of the
+    /// sources are not loaded again.
+    ///
+    /// The location of a schema is only a hint, so schemas that fail to load
+    /// are skipped. [Schemas::validate](crate::xsd::Schemas::validate) reports them.
+    ///
+    /// Each document is parsed to find its references, and the tree is then
+    /// dropped, since it borrows the text the sources own. [WsdlSet::parse]
+    /// parses the documents a second time.
+    pub fn add_location<R: WsResolver>(
+        &mut self,
+        resolver: R,
+        location: &str,
+    ) -> Result<&mut Self, WsLoadError> {
+        // Each pending document is loaded from a location relative to the
+        // referring document, and is either required or only hinted at.
+        let mut pending = vec![(None, location.to_string(), true)];
+
+        while let Some((base, location, required)) = pending.pop() {
+            let source = match resolver.resolve(base.as_deref(), &location) {
+                Ok(source) => source,
+                Err(_) if !required => continue,
+                Err(source) => return Err(WsLoadError::Io { location, source }),
+            };
+
+            if let Some(base) = base {
+                self.references.insert((base, location), source.uri.clone());
+            }
+
+            if self.sources.iter().any(|s| s.uri == source.uri) {
+                continue;
+            }
+
+            let document = match Document::parse(&source.text) {
+                Ok(document) => document,
+                Err(_) if !required => continue,
+                Err(e) => {
+                    return Err(WsLoadError::Parse {
+                        uri: source.uri.clone(),
+                        source: e,
+                    })
+                }
+            };
+
+            let root = document.root_element();
+            let mut schemas = Vec::new();
+            if let Ok(def) = WsDefinitions::from_node(root) {
+                for import in def.imports().into_iter().flatten() {
+                    if let Some(location) = import.location() {
+                        pending.push((Some(source.uri.clone()), location.to_string(), true));
+                    }
+                }
+
+                if let Ok(Some(types)) = def.types() {
+                    schemas.extend(types.schemas().into_iter().flatten());
+                }
+            } else if let Ok(schema) = Schema::from_node(root) {
+                schemas.push(schema);
+            }
+
+            for reference in schemas.iter().flat_map(|s| s.references()) {
+                if let Some(location) = reference.location() {
+                    pending.push((Some(source.uri.clone()), location.to_string(), false));
+                }
+            }
+
+            self.sources.push(source);
+        }
+
+        Ok(self)
+    }
+
+    /// Add an already-loaded document. Its imports are not followed.
+    pub fn add(&mut self, source: WsSource) -> &mut Self {
+        self.sources.push(source);
+        self
+    }
+
+    /// Retrieve the documents gathered so far, in load order.
+    pub fn sources(&self) -> impl Iterator<Item = &WsSource> {
+        self.sources.iter()
+    }
+}
+
+/// A set of parsed WSDL documents. References made by definitions obtained
+/// from the set resolve across every document in it, so messages, port types
+/// and bindings may live in different files.
+#[derive(Debug)]
+pub struct WsdlSet<'input> {
+    documents: Vec<(&'input str, Document<'input>)>,
+    references: &'input HashMap<(String, String), String>,
+}
+
+impl<'input> WsdlSet<'input> {
+    /// Parse every document of the given sources. Documents gathered by
+    /// [WsdlSources::add_location] are known to parse, so this only fails for
+    /// documents added with [WsdlSources::add].
+    pub fn parse(sources: &'input WsdlSources) -> Result<Self, WsLoadError> {
+        let documents = sources
+            .sources
+            .iter()
+            .map(|s| {
+                Document::parse(&s.text)
+                    .map(|d| (s.uri.as_str(), d))
+                    .map_err(|e| WsLoadError::Parse {
+                        uri: s.uri.clone(),
+                        source: e,
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            documents,
+            references: &sources.references,
+        })
+    }
+
+    /// Retrieve the parsed documents along with their URIs.
+    pub fn documents(&self) -> impl Iterator<Item = (&'input str, &Document<'input>)> {
+        self.documents.iter().map(|(uri, d)| (*uri, d))
+    }
+
+    /// Retrieve the definitions of the first document in the set, which is
+    /// the document the set was loaded from.
+    pub fn root(&self) -> Option<WsDefinitions<'_, 'input>> {
+        self.definitions().next()
+    }
+
+    /// Retrieve the location of an error raised by definitions of this set,
+    /// naming the document by its URI.
+    pub fn locate(&self, error: &WsError) -> Option<WsLocation<'_>> {
+        self.documents()
+            .find(|(_, d)| error.is_from(d))
+            .map(|(uri, _)| error.locate(uri))
+    }
+
+    /// Retrieve the standalone schema documents of the set. Schemas embedded
+    /// in WSDL documents are not included.
+    pub fn schemas(&self) -> impl Iterator<Item = Schema<'_, 'input>> {
+        self.documents
+            .iter()
+            .filter_map(|(_, d)| Schema::from_node(d.root_element()).ok())
+    }
+
+    /// Find the document that a node of the set refers to by `location`,
+    /// if it was loaded.
+    pub(crate) fn referenced(&self, node: Node, location: &str) -> Option<&Document<'input>> {
+        let text = node.document().input_text().as_ptr();
+        let (base, _) = self
+            .documents()
+            .find(|(_, d)| d.input_text().as_ptr() == text)?;
+
+        // Documents added without following references are found by the URI
+        // the built-in resolvers would have given them.
+        let uri = self
+            .references
+            .get(&(base.to_string(), location.to_string()))
+            .cloned()
+            .unwrap_or_else(|| resolve_uri(Some(base), location));
+
+        self.documents().find(|(u, _)| *u == uri).map(|(_, d)| d)
+    }
+
+    /// Retrieve the definitions of every WSDL document in the set.
+    pub fn definitions(&self) -> impl Iterator<Item = WsDefinitions<'_, 'input>> {
+        self.documents
+            .iter()
+            .filter_map(|(_, d)| WsDefinitions::with_scope(d.root_element(), Scope::Set(self)).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemoryResolver;
+
+    const SERVICE: &str = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+            xmlns:msg="urn:messages" xmlns:tns="urn:service" targetNamespace="urn:service">
+          <import namespace="urn:messages" location="../common/messages.wsdl"/>
+          <portType name="Port">
+            <operation name="Echo">
+              <input message="msg:Request"/>
+              <output message="msg:Response"/>
+            </operation>
+          </portType>
+        </definitions>"#;
+
+    // Imports the service back, closing the cycle.
+    const MESSAGES: &str = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+            targetNamespace="urn:messages">
+          <import namespace="urn:service" location="../services/service.wsdl"/>
+          <import namespace="urn:faults" location="./faults.wsdl"/>
+          <message name="Request"/>
+          <message name="Response"/>
+        </definitions>"#;
+
+    const FAULTS: &str = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+            targetNamespace="urn:faults">
+          <message name="Fault"/>
+        </definitions>"#;
+
+    fn resolver() -> MemoryResolver {
+        let mut resolver = MemoryResolver::new();
+        resolver
+            .insert("wsdl/services/service.wsdl", SERVICE)
+            .insert("wsdl/common/messages.wsdl", MESSAGES)
+            .insert("wsdl/common/faults.wsdl", FAULTS);
+        resolver
+    }
+
+    #[test]
+    fn follow_relative_imports_and_cycles() {
+        let sources = WsdlSources::load(resolver(), "wsdl/services/service.wsdl").unwrap();
+        let uris = sources
+            .sources()
+            .map(|s| s.uri.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            uris,
+            [
+                "wsdl/services/service.wsdl",
+                "wsdl/common/messages.wsdl",
+                "wsdl/common/faults.wsdl",
+            ]
+        );
+
+        let set = WsdlSet::parse(&sources).unwrap();
+        assert_eq!(set.definitions().count(), 3);
+
+        let root = set.root().unwrap();
+        assert_eq!(root.target_namespace(), Some("urn:service"));
+
+        let port_type = root.port_types().unwrap().next().unwrap();
+        let operation = port_type.operations().unwrap().next().unwrap();
+        let input = operation.input().unwrap().unwrap();
+        assert_eq!(input.name().unwrap(), "Request");
+        assert!(set
+            .documents()
+            .any(|(uri, d)| uri == "wsdl/common/messages.wsdl"
+                && std::ptr::eq(d, input.node().document())));
+    }
+
+    #[test]
+    fn report_missing_and_malformed_imports() {
+        let mut resolver = resolver();
+        resolver.insert("wsdl/common/faults.wsdl", "<definitions");
+
+        match WsdlSources::load(&resolver, "wsdl/services/service.wsdl") {
+            Err(WsLoadError::Parse { uri, .. }) => assert_eq!(uri, "wsdl/common/faults.wsdl"),
+            other => panic!("unexpected result {other:?}"),
+        }
+
+        match WsdlSources::load(&resolver, "wsdl/common/missing.wsdl") {
+            Err(WsLoadError::Io { location, .. }) => {
+                assert_eq!(location, "wsdl/common/missing.wsdl")
+            }
+            other => panic!("unexpected result {other:?}"),
+        }
+
+        let mut sources = WsdlSources::new();
+        sources.add(WsSource {
+            uri: "added.wsdl".to_string(),
+            text: "<definitions".to_string(),
+        });
+        assert!(matches!(
+            WsdlSet::parse(&sources),
+            Err(WsLoadError::Parse { uri, .. }) if uri == "added.wsdl"
+        ));
+    }
+}
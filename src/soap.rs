@@ -0,0 +1,714 @@
+use roxmltree::{ExpandedName, Node};
+
+use crate::wsdl::{
+    invalid_attribute, lookup_qualified, required_attribute, Result, Scope, WsComponentKind,
+    WsErrorType,
+};
+use crate::{
+    WsBinding, WsBindingMessage, WsBindingOperation, WsError, WsMessage, WsService, WsServicePort,
+};
+
+// The SOAP binding extensions, as defined in section 3 of the WSDL 1.1 specification.
+const SOAP_NS: &str = "http://schemas.xmlsoap.org/wsdl/soap/";
+// The SOAP 1.2 binding extensions, as defined by the W3C "WSDL 1.1 Binding Extension for SOAP 1.2" note.
+const SOAP12_NS: &str = "http://schemas.xmlsoap.org/wsdl/soap12/";
+
+/// The version of SOAP a binding extension element describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum SoapVersion {
+    /// SOAP 1.1, described by the `http://schemas.xmlsoap.org/wsdl/soap/` namespace.
+    Soap11,
+    /// SOAP 1.2, described by the `http://schemas.xmlsoap.org/wsdl/soap12/` namespace.
+    Soap12,
+}
+
+impl SoapVersion {
+    /// Retrieve the namespace of the WSDL binding extensions for this version.
+    pub fn binding_namespace(&self) -> &'static str {
+        match self {
+            SoapVersion::Soap11 => SOAP_NS,
+            SoapVersion::Soap12 => SOAP12_NS,
+        }
+    }
+
+    /// Retrieve the namespace of the SOAP envelope for this version.
+    pub fn envelope_namespace(&self) -> &'static str {
+        match self {
+            SoapVersion::Soap11 => "http://schemas.xmlsoap.org/soap/envelope/",
+            SoapVersion::Soap12 => "http://www.w3.org/2003/05/soap-envelope",
+        }
+    }
+
+    /// Retrieve the media type of messages for this version.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            SoapVersion::Soap11 => "text/xml",
+            SoapVersion::Soap12 => "application/soap+xml",
+        }
+    }
+
+    /// Retrieve the name of the element within a SOAP fault that carries the
+    /// fault detail described by a [SoapFault]. SOAP 1.1 uses an unqualified
+    /// `detail`, while SOAP 1.2 uses `env:Detail` inside `env:Fault`.
+    pub fn fault_detail(&self) -> ExpandedName<'static, 'static> {
+        match self {
+            SoapVersion::Soap11 => "detail".into(),
+            SoapVersion::Soap12 => ExpandedName::from_static(self.envelope_namespace(), "Detail"),
+        }
+    }
+}
+
+impl std::fmt::Display for SoapVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SoapVersion::Soap11 => f.write_str("SOAP 1.1"),
+            SoapVersion::Soap12 => f.write_str("SOAP 1.2"),
+        }
+    }
+}
+
+/// The style of a SOAP binding or operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum SoapStyle {
+    /// The body contains a wrapper element named after the operation, with
+    /// one child element per message part.
+    Rpc,
+    /// The body contains the message parts directly.
+    Document,
+}
+
+/// How the message parts of a SOAP body, header or fault are serialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum SoapUse {
+    /// The parts are serialized exactly as described by their schema.
+    Literal,
+    /// The parts are serialized according to an `encodingStyle`.
+    Encoded,
+}
+
+// Whether the node is the given SOAP extension element, of either version.
+fn is_soap(node: &Node, tag: &str) -> bool {
+    node.has_tag_name((SOAP_NS, tag)) || node.has_tag_name((SOAP12_NS, tag))
+}
+
+fn soap_child<'a, 'input>(node: Node<'a, 'input>, tag: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|n| is_soap(n, tag))
+}
+
+fn version(node: Node) -> SoapVersion {
+    match node.tag_name().namespace() {
+        Some(SOAP12_NS) => SoapVersion::Soap12,
+        _ => SoapVersion::Soap11,
+    }
+}
+
+fn style(node: Node) -> Result<Option<SoapStyle>> {
+    match node.attribute("style") {
+        Some("rpc") => Ok(Some(SoapStyle::Rpc)),
+        Some("document") => Ok(Some(SoapStyle::Document)),
+        Some(_) => Err(invalid_attribute(node, "style")),
+        None => Ok(None),
+    }
+}
+
+fn usage(node: Node) -> Result<Option<SoapUse>> {
+    match node.attribute("use") {
+        Some("literal") => Ok(Some(SoapUse::Literal)),
+        Some("encoded") => Ok(Some(SoapUse::Encoded)),
+        Some(_) => Err(invalid_attribute(node, "use")),
+        None => Ok(None),
+    }
+}
+
+fn encoding_style<'a>(node: Node<'a, '_>) -> Option<Vec<&'a str>> {
+    node.attribute("encodingStyle")
+        .map(|s| s.split_whitespace().collect())
+}
+
+/// The `soap:binding` element, which marks a [WsBinding] as a SOAP binding.
+#[derive(Debug, Clone)]
+pub struct SoapBinding<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> SoapBinding<'a, 'input> {
+    /// Retrieve the URI of the transport SOAP is carried over, such as
+    /// `http://schemas.xmlsoap.org/soap/http`.
+    pub fn transport(&self) -> Result<&'a str> {
+        required_attribute(self.0, "transport")
+    }
+
+    /// Retrieve the default style of the binding's operations. This
+    /// defaults to [SoapStyle::Document].
+    pub fn style(&self) -> Result<SoapStyle> {
+        Ok(style(self.0)?.unwrap_or(SoapStyle::Document))
+    }
+
+    /// Retrieve the SOAP version this element describes.
+    pub fn version(&self) -> SoapVersion {
+        version(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// The `soap:operation` element of a [WsBindingOperation].
+#[derive(Debug, Clone)]
+pub struct SoapOperation<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> SoapOperation<'a, 'input> {
+    /// Retrieve the value of the `SOAPAction` header for this operation.
+    /// Under SOAP 1.2 this is sent as the `action` parameter of the content type.
+    pub fn soap_action(&self) -> Option<&'a str> {
+        self.0.attribute("soapAction")
+    }
+
+    /// Retrieve whether the `soapAction` must be sent. This is only defined
+    /// for SOAP 1.2 operations.
+    pub fn soap_action_required(&self) -> Result<Option<bool>> {
+        match self.0.attribute("soapActionRequired") {
+            Some("true" | "1") => Ok(Some(true)),
+            Some("false" | "0") => Ok(Some(false)),
+            Some(_) => Err(invalid_attribute(self.0, "soapActionRequired")),
+            None => Ok(None),
+        }
+    }
+
+    /// Retrieve the style of the operation. If the operation does not
+    /// specify one, it is inherited from the enclosing `soap:binding`.
+    pub fn style(&self) -> Result<SoapStyle> {
+        if let Some(style) = style(self.0)? {
+            return Ok(style);
+        }
+
+        let binding = self
+            .0
+            .parent()
+            .and_then(|n| n.parent())
+            .and_then(|n| soap_child(n, "binding"));
+
+        match binding {
+            Some(binding) => SoapBinding(binding).style(),
+            None => Ok(SoapStyle::Document),
+        }
+    }
+
+    /// Retrieve the SOAP version this element describes.
+    pub fn version(&self) -> SoapVersion {
+        version(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// The `soap:body` element of a [WsBindingMessage], which describes how
+/// message parts appear inside the SOAP body.
+#[derive(Debug, Clone)]
+pub struct SoapBody<'a, 'input>(pub(crate) Node<'a, 'input>);
+
+impl<'a, 'input> SoapBody<'a, 'input> {
+    /// Retrieve how the parts are serialized.
+    pub fn usage(&self) -> Result<Option<SoapUse>> {
+        usage(self.0)
+    }
+
+    /// Retrieve the names of the message parts that appear in the body.
+    /// If unspecified, every part of the message appears.
+    pub fn parts(&self) -> Option<Vec<&'a str>> {
+        self.0
+            .attribute("parts")
+            .map(|s| s.split_whitespace().collect())
+    }
+
+    /// Retrieve the namespace of the operation wrapper element for
+    /// RPC-style or encoded bodies.
+    pub fn namespace(&self) -> Option<&'a str> {
+        self.0.attribute("namespace")
+    }
+
+    /// Retrieve the URIs of the encodings used for encoded bodies.
+    pub fn encoding_style(&self) -> Option<Vec<&'a str>> {
+        encoding_style(self.0)
+    }
+
+    /// Retrieve the SOAP version this element describes.
+    pub fn version(&self) -> SoapVersion {
+        version(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// The `soap:header` element of a [WsBindingMessage], which places a
+/// message part in a SOAP header.
+#[derive(Debug, Clone)]
+pub struct SoapHeader<'a, 'input>(Node<'a, 'input>, Scope<'a, 'input>);
+
+impl<'a, 'input> SoapHeader<'a, 'input> {
+    /// Retrieve the message that contains the header part.
+    pub fn message(&self) -> Result<WsMessage<'a, 'input>> {
+        let message_typename = required_attribute(self.0, "message")?;
+        lookup_qualified(self.0, self.1, message_typename, WsComponentKind::Message)
+            .map(|n| WsMessage(n, self.1))
+    }
+
+    /// Retrieve the name of the message part that appears in the header.
+    pub fn part(&self) -> Result<&'a str> {
+        required_attribute(self.0, "part")
+    }
+
+    /// Retrieve how the part is serialized.
+    pub fn usage(&self) -> Result<Option<SoapUse>> {
+        usage(self.0)
+    }
+
+    /// Retrieve the namespace of encoded headers.
+    pub fn namespace(&self) -> Option<&'a str> {
+        self.0.attribute("namespace")
+    }
+
+    /// Retrieve the URIs of the encodings used for encoded headers.
+    pub fn encoding_style(&self) -> Option<Vec<&'a str>> {
+        encoding_style(self.0)
+    }
+
+    /// Retrieve the faults that may be returned in headers when processing
+    /// this header.
+    pub fn header_faults(&self) -> impl Iterator<Item = SoapHeaderFault<'a, 'input>> {
+        let scope = self.1;
+        self.0
+            .children()
+            .filter(|n| is_soap(n, "headerfault"))
+            .map(move |n| SoapHeaderFault(n, scope))
+    }
+
+    /// Retrieve the SOAP version this element describes.
+    pub fn version(&self) -> SoapVersion {
+        version(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// The `soap:headerfault` element of a [SoapHeader].
+#[derive(Debug, Clone)]
+pub struct SoapHeaderFault<'a, 'input>(Node<'a, 'input>, Scope<'a, 'input>);
+
+impl<'a, 'input> SoapHeaderFault<'a, 'input> {
+    /// Retrieve the message that contains the header fault part.
+    pub fn message(&self) -> Result<WsMessage<'a, 'input>> {
+        let message_typename = required_attribute(self.0, "message")?;
+        lookup_qualified(self.0, self.1, message_typename, WsComponentKind::Message)
+            .map(|n| WsMessage(n, self.1))
+    }
+
+    /// Retrieve the name of the message part that carries the fault.
+    pub fn part(&self) -> Result<&'a str> {
+        required_attribute(self.0, "part")
+    }
+
+    /// Retrieve how the part is serialized.
+    pub fn usage(&self) -> Result<Option<SoapUse>> {
+        usage(self.0)
+    }
+
+    /// Retrieve the namespace of encoded header faults.
+    pub fn namespace(&self) -> Option<&'a str> {
+        self.0.attribute("namespace")
+    }
+
+    /// Retrieve the URIs of the encodings used for encoded header faults.
+    pub fn encoding_style(&self) -> Option<Vec<&'a str>> {
+        encoding_style(self.0)
+    }
+
+    /// Retrieve the SOAP version this element describes.
+    pub fn version(&self) -> SoapVersion {
+        version(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// The `soap:fault` element of a fault [WsBindingMessage], which describes
+/// the contents of the SOAP fault detail. See [SoapVersion::fault_detail] for
+/// where the detail appears in each version of the SOAP fault.
+#[derive(Debug, Clone)]
+pub struct SoapFault<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> SoapFault<'a, 'input> {
+    /// Retrieve the name of the fault. This matches the name of the
+    /// enclosing `wsdl:fault`.
+    pub fn name(&self) -> Result<&'a str> {
+        required_attribute(self.0, "name")
+    }
+
+    /// Retrieve how the fault detail is serialized.
+    pub fn usage(&self) -> Result<Option<SoapUse>> {
+        usage(self.0)
+    }
+
+    /// Retrieve the namespace of encoded faults.
+    pub fn namespace(&self) -> Option<&'a str> {
+        self.0.attribute("namespace")
+    }
+
+    /// Retrieve the URIs of the encodings used for encoded faults.
+    pub fn encoding_style(&self) -> Option<Vec<&'a str>> {
+        encoding_style(self.0)
+    }
+
+    /// Retrieve the SOAP version this element describes.
+    pub fn version(&self) -> SoapVersion {
+        version(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// The `soap:address` element of a [WsServicePort].
+#[derive(Debug, Clone)]
+pub struct SoapAddress<'a, 'input>(pub(crate) Node<'a, 'input>);
+
+impl<'a, 'input> SoapAddress<'a, 'input> {
+    /// Retrieve the URL of the port's endpoint.
+    pub fn location(&self) -> Result<&'a str> {
+        required_attribute(self.0, "location")
+    }
+
+    /// Retrieve the SOAP version this element describes.
+    pub fn version(&self) -> SoapVersion {
+        version(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+impl<'a, 'input> WsBinding<'a, 'input> {
+    /// Retrieve the `soap:binding` or `soap12:binding` of this binding, if it
+    /// is a SOAP binding.
+    pub fn soap_binding(&self) -> Result<Option<SoapBinding<'a, 'input>>> {
+        Ok(soap_child(self.0, "binding").map(SoapBinding))
+    }
+
+    /// Retrieve the SOAP version of this binding, if it is a SOAP binding.
+    pub fn soap_version(&self) -> Result<Option<SoapVersion>> {
+        Ok(self.soap_binding()?.map(|b| b.version()))
+    }
+}
+
+impl<'a, 'input> WsBindingOperation<'a, 'input> {
+    /// Retrieve the `soap:operation` of this operation, if any.
+    pub fn soap_operation(&self) -> Result<Option<SoapOperation<'a, 'input>>> {
+        Ok(soap_child(self.0, "operation").map(SoapOperation))
+    }
+}
+
+impl<'a, 'input> WsBindingMessage<'a, 'input> {
+    /// Retrieve the `soap:body` of this message, if any.
+    pub fn soap_body(&self) -> Result<Option<SoapBody<'a, 'input>>> {
+        Ok(soap_child(self.0, "body").map(SoapBody))
+    }
+
+    /// Retrieve the `soap:header` elements of this message.
+    pub fn soap_headers(&self) -> Result<impl Iterator<Item = SoapHeader<'a, 'input>>> {
+        let scope = self.1;
+        Ok(self
+            .0
+            .children()
+            .filter(|n| is_soap(n, "header"))
+            .map(move |n| SoapHeader(n, scope)))
+    }
+
+    /// Retrieve the `soap:fault` of this message, if it is a fault.
+    pub fn soap_fault(&self) -> Result<Option<SoapFault<'a, 'input>>> {
+        Ok(soap_child(self.0, "fault").map(SoapFault))
+    }
+}
+
+impl<'a, 'input> WsServicePort<'a, 'input> {
+    /// Retrieve the `soap:address` or `soap12:address` of this port, if any.
+    pub fn soap_address(&self) -> Result<Option<SoapAddress<'a, 'input>>> {
+        Ok(soap_child(self.0, "address").map(SoapAddress))
+    }
+
+    /// Retrieve the binding of this port, provided it is a SOAP binding of the
+    /// requested version.
+    pub fn soap_binding(&self, version: SoapVersion) -> Result<WsBinding<'a, 'input>> {
+        let binding = self.binding()?;
+
+        match binding.soap_version()? {
+            Some(v) if v == version => Ok(binding),
+            _ => Err(WsError::new(
+                self.0,
+                WsErrorType::UnsupportedSoapVersion(version),
+            )),
+        }
+    }
+}
+
+impl<'a, 'input> WsService<'a, 'input> {
+    /// Retrieve the first port of this service that is bound to the requested
+    /// SOAP version. Ports whose binding cannot be resolved are skipped; if no
+    /// port matches, the first such error is returned rather than reporting
+    /// the version as unsupported.
+    pub fn soap_port(&self, version: SoapVersion) -> Result<WsServicePort<'a, 'input>> {
+        let mut error = None;
+        for port in self.ports()? {
+            match port.binding().and_then(|b| b.soap_version()) {
+                Ok(v) if v == Some(version) => return Ok(port),
+                Ok(_) => {}
+                Err(e) => {
+                    error.get_or_insert(e);
+                }
+            }
+        }
+
+        Err(error
+            .unwrap_or_else(|| WsError::new(self.0, WsErrorType::UnsupportedSoapVersion(version))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WsDefinitions;
+    use roxmltree::Document;
+
+    const SERVICE: &str = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+            xmlns:soap="http://schemas.xmlsoap.org/wsdl/soap/"
+            xmlns:a="urn:a" targetNamespace="urn:a">
+          <message name="Request"><part name="body" type="a:Body"/></message>
+          <message name="Session"><part name="id" type="a:Id"/></message>
+          <message name="Expired"><part name="detail" type="a:Detail"/></message>
+          <portType name="Port">
+            <operation name="Echo">
+              <input message="a:Request"/>
+              <output message="a:Request"/>
+              <fault name="Failed" message="a:Expired"/>
+            </operation>
+            <operation name="Inherit"><input message="a:Request"/></operation>
+            <operation name="Invalid"><input message="a:Request"/></operation>
+          </portType>
+          <binding name="Binding" type="a:Port">
+            <soap:binding style="rpc" transport="http://schemas.xmlsoap.org/soap/http"/>
+            <operation name="Echo">
+              <soap:operation soapAction="urn:a#Echo" style="document"/>
+              <input>
+                <soap:body use="encoded" parts="body" namespace="urn:a:wrapper"
+                    encodingStyle="urn:first urn:second"/>
+                <soap:header message="a:Session" part="id" use="literal">
+                  <soap:headerfault message="a:Expired" part="detail" use="literal"/>
+                </soap:header>
+              </input>
+              <output><soap:body use="literal"/></output>
+              <fault name="Failed"><soap:fault name="Failed" use="literal"/></fault>
+            </operation>
+            <operation name="Inherit">
+              <soap:operation/>
+              <input><soap:body/></input>
+            </operation>
+            <operation name="Invalid">
+              <soap:operation style="document-ish"/>
+              <input><soap:body use="plain"/></input>
+            </operation>
+          </binding>
+          <service name="Service">
+            <port name="Port" binding="a:Binding">
+              <soap:address location="http://example.com/echo"/>
+            </port>
+          </service>
+        </definitions>"#;
+
+    fn binding_operation<'a, 'input>(
+        binding: &WsBinding<'a, 'input>,
+        name: &str,
+    ) -> WsBindingOperation<'a, 'input> {
+        let mut operations = binding.operations().unwrap();
+        operations.find(|o| o.name().unwrap() == name).unwrap()
+    }
+
+    #[test]
+    fn read_soap_extensions() {
+        let document = Document::parse(SERVICE).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let binding = definitions.bindings().unwrap().next().unwrap();
+
+        let soap_binding = binding.soap_binding().unwrap().unwrap();
+        assert_eq!(
+            soap_binding.transport().unwrap(),
+            "http://schemas.xmlsoap.org/soap/http"
+        );
+        assert_eq!(soap_binding.style().unwrap(), SoapStyle::Rpc);
+
+        let echo = binding_operation(&binding, "Echo");
+        let operation = echo.soap_operation().unwrap().unwrap();
+        assert_eq!(operation.soap_action(), Some("urn:a#Echo"));
+        assert_eq!(operation.style().unwrap(), SoapStyle::Document);
+
+        let input = echo.input().unwrap().unwrap();
+        let body = input.soap_body().unwrap().unwrap();
+        assert_eq!(body.usage().unwrap(), Some(SoapUse::Encoded));
+        assert_eq!(body.parts(), Some(vec!["body"]));
+        assert_eq!(body.namespace(), Some("urn:a:wrapper"));
+        assert_eq!(body.encoding_style(), Some(vec!["urn:first", "urn:second"]));
+        assert!(input.soap_fault().unwrap().is_none());
+
+        let headers: Vec<_> = input.soap_headers().unwrap().collect();
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers[0].message().unwrap().name().unwrap(), "Session");
+        assert_eq!(headers[0].part().unwrap(), "id");
+        assert_eq!(headers[0].usage().unwrap(), Some(SoapUse::Literal));
+        let header_fault = headers[0].header_faults().next().unwrap();
+        assert_eq!(header_fault.message().unwrap().name().unwrap(), "Expired");
+        assert_eq!(header_fault.part().unwrap(), "detail");
+
+        let output = echo.output().unwrap().unwrap();
+        assert_eq!(output.soap_headers().unwrap().count(), 0);
+        let body = output.soap_body().unwrap().unwrap();
+        assert_eq!(body.usage().unwrap(), Some(SoapUse::Literal));
+        assert_eq!(body.parts(), None);
+
+        let fault = echo.faults().unwrap().next().unwrap();
+        let soap_fault = fault.soap_fault().unwrap().unwrap();
+        assert_eq!(soap_fault.name().unwrap(), "Failed");
+        assert_eq!(soap_fault.usage().unwrap(), Some(SoapUse::Literal));
+
+        let service = definitions.services().unwrap().next().unwrap();
+        let port = service.ports().unwrap().next().unwrap();
+        let address = port.soap_address().unwrap().unwrap();
+        assert_eq!(address.location().unwrap(), "http://example.com/echo");
+    }
+
+    #[test]
+    fn inherit_defaults_and_reject_invalid_values() {
+        let document = Document::parse(SERVICE).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let binding = definitions.bindings().unwrap().next().unwrap();
+
+        // The style is inherited from `soap:binding`.
+        let inherit = binding_operation(&binding, "Inherit");
+        let operation = inherit.soap_operation().unwrap().unwrap();
+        assert_eq!(operation.soap_action(), None);
+        assert_eq!(operation.style().unwrap(), SoapStyle::Rpc);
+        let body = inherit.input().unwrap().unwrap().soap_body().unwrap();
+        assert_eq!(body.unwrap().usage().unwrap(), None);
+
+        let invalid = binding_operation(&binding, "Invalid");
+        assert!(invalid.soap_operation().unwrap().unwrap().style().is_err());
+        let body = invalid.input().unwrap().unwrap().soap_body().unwrap();
+        assert!(body.unwrap().usage().is_err());
+
+        // Without a `soap:binding`, the style defaults to document.
+        let text = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+                xmlns:soap="http://schemas.xmlsoap.org/wsdl/soap/">
+              <binding name="Binding">
+                <operation name="Echo"><soap:operation/></operation>
+              </binding>
+            </definitions>"#;
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let binding = definitions.bindings().unwrap().next().unwrap();
+        assert!(binding.soap_binding().unwrap().is_none());
+        let operation = binding_operation(&binding, "Echo")
+            .soap_operation()
+            .unwrap();
+        assert_eq!(operation.unwrap().style().unwrap(), SoapStyle::Document);
+    }
+
+    #[test]
+    fn choose_port_by_soap_version() {
+        let text = include_str!("../assets/weather/weather.wsdl");
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let service = definitions.services().unwrap().next().unwrap();
+
+        for (version, name) in [
+            (SoapVersion::Soap11, "WeatherSoap"),
+            (SoapVersion::Soap12, "WeatherSoap12"),
+        ] {
+            let port = service.soap_port(version).unwrap();
+            assert_eq!(port.name().unwrap(), name);
+            assert_eq!(port.soap_address().unwrap().unwrap().version(), version);
+            let binding = port.soap_binding(version).unwrap();
+            assert_eq!(binding.name().unwrap(), name);
+            assert_eq!(binding.soap_version().unwrap(), Some(version));
+        }
+
+        let port = service.soap_port(SoapVersion::Soap11).unwrap();
+        let error = port.soap_binding(SoapVersion::Soap12).unwrap_err();
+        assert!(matches!(
+            error.error_type(),
+            WsErrorType::UnsupportedSoapVersion(SoapVersion::Soap12)
+        ));
+
+        // The SOAP 1.1 service has no SOAP 1.2 port.
+        let document = Document::parse(SERVICE).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let service = definitions.services().unwrap().next().unwrap();
+        let error = service.soap_port(SoapVersion::Soap12).unwrap_err();
+        assert!(matches!(
+            error.error_type(),
+            WsErrorType::UnsupportedSoapVersion(SoapVersion::Soap12)
+        ));
+    }
+
+    #[test]
+    fn skip_ports_with_unresolved_bindings() {
+        let text = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+                xmlns:soap="http://schemas.xmlsoap.org/wsdl/soap/"
+                xmlns:a="urn:a" targetNamespace="urn:a">
+              <binding name="Binding">
+                <soap:binding transport="http://schemas.xmlsoap.org/soap/http"/>
+              </binding>
+              <service name="Service">
+                <port name="Broken" binding="a:Missing"/>
+                <port name="Port" binding="a:Binding"/>
+              </service>
+              <service name="Unresolved">
+                <port name="Broken" binding="a:Missing"/>
+              </service>
+            </definitions>"#;
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let mut services = definitions.services().unwrap();
+
+        let service = services.next().unwrap();
+        let port = service.soap_port(SoapVersion::Soap11).unwrap();
+        assert_eq!(port.name().unwrap(), "Port");
+
+        // The unresolved binding is the better explanation.
+        let service = services.next().unwrap();
+        let error = service.soap_port(SoapVersion::Soap11).unwrap_err();
+        assert!(!matches!(
+            error.error_type(),
+            WsErrorType::UnsupportedSoapVersion(_)
+        ));
+        assert!(error.to_string().contains("Missing"), "{error}");
+    }
+}
@@ -0,0 +1,529 @@
+use std::collections::HashSet;
+
+use roxmltree::Node;
+
+use crate::owned::QName;
+use crate::wsdl::{Result, WsComponentKind, WsErrorMalformedType, WsErrorType};
+use crate::xsd::Schemas;
+use crate::{MimeElement, WsBindingMessage, WsDefinitions, WsError, WsMessage};
+
+/// How serious a [WsDiagnostic] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WsSeverity {
+    /// The definitions can be read, but are unlikely to be what was intended.
+    Warning,
+    /// The definitions are invalid, and part of them cannot be read.
+    Error,
+}
+
+impl std::fmt::Display for WsSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Warning => "warning",
+            Self::Error => "error",
+        })
+    }
+}
+
+/// A problem found by [WsDefinitions::validate].
+#[derive(Debug)]
+pub struct WsDiagnostic {
+    pub severity: WsSeverity,
+    /// The problem itself, which records the node it was found at.
+    pub error: WsError,
+}
+
+impl WsDiagnostic {
+    pub fn is_error(&self) -> bool {
+        self.severity == WsSeverity::Error
+    }
+}
+
+#[cfg(feature = "codespan")]
+impl WsDiagnostic {
+    /// Convert the diagnostic for rendering with `codespan-reporting`. See
+    /// [WsError::to_diagnostic].
+    pub fn to_diagnostic<FileId>(
+        &self,
+        file_id: FileId,
+    ) -> codespan_reporting::diagnostic::Diagnostic<FileId> {
+        use codespan_reporting::diagnostic::Severity;
+
+        let mut diagnostic = self.error.to_diagnostic(file_id);
+        diagnostic.severity = match self.severity {
+            WsSeverity::Warning => Severity::Warning,
+            WsSeverity::Error => Severity::Error,
+        };
+
+        diagnostic
+    }
+}
+
+impl std::fmt::Display for WsDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.severity, self.error)
+    }
+}
+
+// Collects the problems of a document, carrying on past each one.
+#[derive(Default)]
+struct Validator {
+    diagnostics: Vec<WsDiagnostic>,
+}
+
+impl Validator {
+    fn report(&mut self, severity: WsSeverity, error: WsError) {
+        self.diagnostics.push(WsDiagnostic { severity, error });
+    }
+
+    // Record the error of a failed lookup, and carry on with its value otherwise.
+    fn check<T>(&mut self, result: Result<T>) -> Option<T> {
+        result.map_err(|e| self.report(WsSeverity::Error, e)).ok()
+    }
+
+    // Report every node that reuses the name of an earlier one. Nodes without
+    // a name are skipped, as they are reported by the component itself.
+    fn duplicates<'a, 'input: 'a>(
+        &mut self,
+        nodes: impl Iterator<Item = Node<'a, 'input>>,
+        namespace: Option<&str>,
+        kind: WsComponentKind,
+    ) {
+        let mut names = HashSet::new();
+        for node in nodes {
+            let Some(name) = node.attribute("name") else {
+                continue;
+            };
+
+            if !names.insert(name) {
+                self.report(
+                    WsSeverity::Error,
+                    WsError::new(
+                        node,
+                        WsErrorType::DuplicateDefinition {
+                            name: QName::within(namespace, name),
+                            kind,
+                        },
+                    ),
+                );
+            }
+        }
+    }
+
+    // Report the names that a binding element mentions, but the message lacks.
+    fn parts<'n>(
+        &mut self,
+        node: Node,
+        message: &WsMessage,
+        names: impl IntoIterator<Item = &'n str>,
+    ) {
+        for name in names {
+            if message.parts().any(|p| p.name().ok() == Some(name)) {
+                continue;
+            }
+
+            let namespace = message
+                .node()
+                .parent()
+                .and_then(|n| n.attribute("targetNamespace"));
+
+            self.report(
+                WsSeverity::Error,
+                WsError::new(
+                    node,
+                    WsErrorType::UndeclaredPart {
+                        message: QName::within(namespace, message.name().unwrap_or_default()),
+                        part: name.to_string(),
+                    },
+                ),
+            );
+        }
+    }
+
+    // Check the parts mentioned by the input or output of a binding operation,
+    // given the message of the corresponding port operation.
+    fn binding_message(&mut self, binding: &WsBindingMessage, message: Option<&WsMessage>) {
+        // Headers name their own message, which may differ from the body's.
+        for header in self.check(binding.soap_headers()).into_iter().flatten() {
+            if let (Some(message), Some(part)) =
+                (self.check(header.message()), self.check(header.part()))
+            {
+                self.parts(header.node(), &message, [part]);
+            }
+
+            for fault in header.header_faults() {
+                if let (Some(message), Some(part)) =
+                    (self.check(fault.message()), self.check(fault.part()))
+                {
+                    self.parts(fault.node(), &message, [part]);
+                }
+            }
+        }
+
+        let Some(message) = message else {
+            return;
+        };
+
+        if let Some(body) = self.check(binding.soap_body()).flatten() {
+            self.parts(body.node(), message, body.parts().unwrap_or_default());
+        }
+
+        for element in self.check(binding.mime()).into_iter().flatten() {
+            self.mime(element, message);
+        }
+    }
+
+    fn mime(&mut self, element: MimeElement, message: &WsMessage) {
+        match element {
+            MimeElement::Content(content) => {
+                self.parts(content.node(), message, content.part());
+            }
+            MimeElement::MimeXml(xml) => {
+                self.parts(xml.node(), message, xml.part());
+            }
+            MimeElement::MultipartRelated(related) => {
+                for part in related.parts() {
+                    self.check(part.name());
+                    if let Some(body) = self.check(part.soap_body()).flatten() {
+                        self.parts(body.node(), message, body.parts().unwrap_or_default());
+                    }
+
+                    for element in part.elements() {
+                        self.mime(element, message);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a, 'input> WsDefinitions<'a, 'input> {
+    /// Check the definitions for defects, such as references that do not
+    /// resolve, or duplicate names. Unlike the accessors, this carries on
+    /// past each problem, and reports all of them in document order.
+    pub fn validate(&self) -> Vec<WsDiagnostic> {
+        let mut v = Validator::default();
+        let namespace = self.target_namespace();
+
+        // References to the components of these definitions can't be written
+        // without a namespace, so they are unusable from other documents.
+        if namespace.is_none() {
+            v.report(
+                WsSeverity::Warning,
+                WsError::new(
+                    self.0,
+                    WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
+                        "targetNamespace".to_string(),
+                    )),
+                ),
+            );
+        }
+
+        for kind in [
+            WsComponentKind::Message,
+            WsComponentKind::PortType,
+            WsComponentKind::Binding,
+            WsComponentKind::Service,
+        ] {
+            let nodes = self
+                .0
+                .children()
+                .filter(move |n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", kind.tag())));
+
+            v.duplicates(nodes, namespace, kind);
+        }
+
+        // Port names are unique across every service of the document.
+        let ports = v
+            .check(self.services())
+            .into_iter()
+            .flatten()
+            .filter_map(|s| s.ports().ok())
+            .flatten()
+            .map(|p| p.node());
+        v.duplicates(ports, namespace, WsComponentKind::Port);
+
+        for message in v.check(self.messages()).into_iter().flatten() {
+            v.check(message.name());
+            v.duplicates(
+                message.parts().map(|p| p.node()),
+                None,
+                WsComponentKind::Part,
+            );
+
+            for part in message.parts() {
+                v.check(part.name());
+
+                let node = part.node();
+                if node.has_attribute("element") && node.has_attribute("type") {
+                    v.report(
+                        WsSeverity::Error,
+                        WsError::new(
+                            node,
+                            WsErrorType::MalformedWsdl(
+                                WsErrorMalformedType::ConflictingAttributes(
+                                    "element".to_string(),
+                                    "type".to_string(),
+                                ),
+                            ),
+                        ),
+                    );
+                } else {
+                    v.check(part.typename());
+                }
+            }
+        }
+
+        for port_type in v.check(self.port_types()).into_iter().flatten() {
+            v.check(port_type.name());
+
+            for operation in v.check(port_type.operations()).into_iter().flatten() {
+                v.check(operation.name());
+                v.check(operation.kind());
+                v.check(operation.input());
+                v.check(operation.output());
+
+                for fault in v.check(operation.faults()).into_iter().flatten() {
+                    v.check(fault.name());
+                    v.check(fault.message());
+                }
+            }
+        }
+
+        for binding in v.check(self.bindings()).into_iter().flatten() {
+            v.check(binding.name());
+            let port_type = v.check(binding.port_type());
+
+            for operation in v.check(binding.operations()).into_iter().flatten() {
+                // A dangling port type is reported once, by the binding.
+                let name = v.check(operation.name());
+                let port_operation = match (&port_type, name) {
+                    (Some(_), Some(_)) => v.check(operation.port_operation()),
+                    _ => None,
+                };
+
+                // Dangling messages were reported along with the port type.
+                let (input, output) = match &port_operation {
+                    Some(o) => (o.input().ok().flatten(), o.output().ok().flatten()),
+                    None => (None, None),
+                };
+
+                if let Some(binding) = v.check(operation.input()).flatten() {
+                    v.binding_message(&binding, input.as_ref());
+                }
+
+                if let Some(binding) = v.check(operation.output()).flatten() {
+                    v.binding_message(&binding, output.as_ref());
+                }
+
+                // Faults are bound by name, unlike the input and output.
+                for fault in v.check(operation.faults()).into_iter().flatten() {
+                    let Some(name) = fault.name() else {
+                        v.report(
+                            WsSeverity::Error,
+                            WsError::new(
+                                fault.node(),
+                                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
+                                    "name".to_string(),
+                                )),
+                            ),
+                        );
+                        continue;
+                    };
+
+                    let Some(port_operation) = &port_operation else {
+                        continue;
+                    };
+
+                    let mut faults = port_operation.faults().into_iter().flatten();
+                    if !faults.any(|f| f.name().ok() == Some(name)) {
+                        let namespace = port_type.as_ref().and_then(|p| p.target_namespace().ok());
+                        v.report(
+                            WsSeverity::Error,
+                            WsError::new(
+                                fault.node(),
+                                WsErrorType::UnknownComponent {
+                                    name: QName::within(namespace, name),
+                                    kind: WsComponentKind::Fault,
+                                },
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        for service in v.check(self.services()).into_iter().flatten() {
+            v.check(service.name());
+
+            for port in v.check(service.ports()).into_iter().flatten() {
+                v.check(port.name());
+                v.check(port.binding());
+            }
+        }
+
+        // The schemas of the other documents of a set are reported along with
+        // the definitions of those documents.
+        if let Some(schemas) = v.check(Schemas::in_scope(self.0, self.1, |s| s.validate())) {
+            let document = self.0.document();
+            v.diagnostics
+                .extend(schemas.into_iter().filter(|d| d.error.is_from(document)));
+        }
+
+        v.diagnostics.sort_by_key(|d| d.error.span().start);
+        v.diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every defect the validator knows about, in one document.
+    const DEFECTS: &str = r#"
+<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+    xmlns:soap="http://schemas.xmlsoap.org/wsdl/soap/"
+    xmlns:mime="http://schemas.xmlsoap.org/wsdl/mime/"
+    xmlns:xs="http://www.w3.org/2001/XMLSchema"
+    xmlns:tns="urn:test" targetNamespace="urn:test">
+  <message name="In">
+    <part name="a" element="tns:A" type="xs:string"/>
+    <part name="b" type="xs:string"/>
+    <part name="b" type="xs:int"/>
+  </message>
+  <message name="Dup"/>
+  <message name="Dup"/>
+  <message name="Out">
+    <part name="c" element="undeclared:C"/>
+    <part name="d"/>
+  </message>
+  <portType name="Port">
+    <operation name="Echo">
+      <input message="tns:In"/>
+      <output message="tns:Nope"/>
+      <fault name="Failed" message="tns:In"/>
+    </operation>
+    <operation name="Attach">
+      <input message="tns:In"/>
+    </operation>
+  </portType>
+  <portType name="Spare"/>
+  <portType name="Spare"/>
+  <binding name="Binding" type="tns:Port">
+    <soap:binding style="document" transport="http://schemas.xmlsoap.org/soap/http"/>
+    <operation name="Echo">
+      <input>
+        <soap:body use="literal" parts="a zz"/>
+        <soap:header message="tns:In" part="nope" use="literal"/>
+      </input>
+      <fault name="Failed"><soap:fault name="Failed" use="literal"/></fault>
+      <fault name="Unknown"/>
+      <fault/>
+    </operation>
+    <operation name="Attach">
+      <input>
+        <mime:multipartRelated>
+          <mime:part name="text"><mime:content part="missing" type="text/plain"/></mime:part>
+        </mime:multipartRelated>
+      </input>
+    </operation>
+    <operation name="Unbound"/>
+  </binding>
+  <binding name="Dangling" type="tns:Nowhere">
+    <operation name="Echo"/>
+  </binding>
+  <service name="S">
+    <port name="P1" binding="tns:Binding"/>
+    <port name="P1" binding="tns:Binding"/>
+    <port name="P2" binding="tns:Missing"/>
+  </service>
+  <service name="S2">
+    <port name="P1" binding="tns:Binding"/>
+  </service>
+</definitions>"#;
+
+    // Validate a document, and describe each diagnostic along with its line.
+    fn validate(text: &str) -> Vec<(u32, String)> {
+        let document = roxmltree::Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let diagnostics = definitions.validate();
+
+        let spans = diagnostics.iter().map(|d| d.error.span().start);
+        assert!(spans.clone().zip(spans.skip(1)).all(|(a, b)| a <= b));
+
+        diagnostics
+            .iter()
+            .map(|d| (d.error.position().row, d.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn report_every_defect_in_order() {
+        let expected = [
+            (8, "error: The input WSDL document was malformed: attributes \"element\" and \"type\" are mutually exclusive"),
+            (10, "error: The part b is defined more than once"),
+            (13, "error: The message {urn:test}Dup is defined more than once"),
+            (15, "error: Prefix \"undeclared\" of element reference \"undeclared:C\" is not declared"),
+            (16, "error: The input WSDL document was malformed: missing attribute \"type\""),
+            (21, "error: Attempt to refer to unknown message {urn:test}Nope"),
+            (29, "error: The portType {urn:test}Spare is defined more than once"),
+            (34, "error: The message {urn:test}In has no part named \"zz\""),
+            (35, "error: The message {urn:test}In has no part named \"nope\""),
+            (38, "error: Attempt to refer to unknown fault {urn:test}Unknown"),
+            (39, "error: The input WSDL document was malformed: missing attribute \"name\""),
+            (44, "error: The message {urn:test}In has no part named \"missing\""),
+            (48, "error: Attempt to refer to unknown operation {urn:test}Unbound"),
+            (50, "error: Attempt to refer to unknown portType {urn:test}Nowhere"),
+            (55, "error: The port {urn:test}P1 is defined more than once"),
+            (56, "error: Attempt to refer to unknown binding {urn:test}Missing"),
+            (59, "error: The port {urn:test}P1 is defined more than once"),
+        ];
+
+        let diagnostics = validate(DEFECTS);
+        let diagnostics = diagnostics
+            .iter()
+            .map(|(line, d)| (*line, d.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(diagnostics, expected);
+    }
+
+    #[test]
+    fn warn_about_missing_target_namespace() {
+        let diagnostics = validate(
+            r#"<wsdl:definitions xmlns:wsdl="http://schemas.xmlsoap.org/wsdl/">
+                 <wsdl:message name="In"/>
+                 <wsdl:portType name="Port">
+                   <wsdl:operation name="Echo"><wsdl:input message="In"/></wsdl:operation>
+                 </wsdl:portType>
+                 <wsdl:service name="Service">
+                   <wsdl:port name="Port" binding="Missing"/>
+                 </wsdl:service>
+               </wsdl:definitions>"#,
+        );
+
+        assert_eq!(
+            diagnostics,
+            [
+                (
+                    1,
+                    "warning: The input WSDL document was malformed: missing attribute \"targetNamespace\"".to_string()
+                ),
+                (
+                    7,
+                    "error: Attempt to refer to unknown binding Missing".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn accept_valid_documents() {
+        for path in [
+            "assets/hello/hello.wsdl",
+            "assets/serversync/serversync.wsd.xml",
+        ] {
+            let text = std::fs::read_to_string(path).unwrap();
+            assert_eq!(validate(&text), [], "{path}");
+        }
+    }
+}
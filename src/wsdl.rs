@@ -1,7 +1,12 @@
-use roxmltree::{Document, ExpandedName, Node, NodeId};
+use std::ops::Range;
+
+use roxmltree::{Document, ExpandedName, Node, NodeId, TextPos};
 use thiserror::Error;
 
-type Result<'a, 'input, T> = std::result::Result<T, WsError>;
+use crate::owned::QName;
+use crate::{xsd, BpAssertion, SoapVersion, WsExtension, WsExtensionError, WsIndex, WsdlSet};
+
+pub(crate) type Result<T> = std::result::Result<T, WsError>;
 
 #[derive(Error, Debug)]
 pub enum WsErrorMalformedType {
@@ -9,24 +14,216 @@ pub enum WsErrorMalformedType {
     MissingAttribute(String),
     #[error("missing element \"{0}\"")]
     MissingElement(String),
+    #[error("invalid value for attribute \"{0}\"")]
+    InvalidAttribute(String),
+    #[error("attributes \"{0}\" and \"{1}\" are mutually exclusive")]
+    ConflictingAttributes(String, String),
+}
+
+/// The kind of component a qualified name refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WsComponentKind {
+    Message,
+    PortType,
+    Binding,
+    Service,
+    /// An operation of a port type or interface.
+    Operation,
+    /// A WSDL 2.0 interface.
+    Interface,
+    /// A fault of a WSDL 1.1 operation, or a WSDL 2.0 interface fault.
+    Fault,
+    /// A part of a message.
+    Part,
+    /// A port of a service.
+    Port,
+    /// A global element declaration of a schema.
+    SchemaElement,
+    /// A global type definition of a schema.
+    SchemaType,
+    /// A model group definition of a schema.
+    SchemaGroup,
+    /// An attribute group definition of a schema.
+    SchemaAttributeGroup,
+    /// A global attribute declaration of a schema.
+    SchemaAttribute,
+}
+
+impl WsComponentKind {
+    // The WSDL 1.1 top-level components, which share a symbol space for
+    // the purpose of reporting references to the wrong kind.
+    pub(crate) const DEFINITIONS: [Self; 4] =
+        [Self::Message, Self::PortType, Self::Binding, Self::Service];
+
+    /// Retrieve the local name of the element that defines the component.
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Self::Message => "message",
+            Self::PortType => "portType",
+            Self::Binding => "binding",
+            Self::Service => "service",
+            Self::Operation => "operation",
+            Self::Interface => "interface",
+            Self::Fault => "fault",
+            Self::Part => "part",
+            Self::Port => "port",
+            Self::SchemaElement => "element",
+            Self::SchemaType => "type",
+            Self::SchemaGroup => "group",
+            Self::SchemaAttributeGroup => "attributeGroup",
+            Self::SchemaAttribute => "attribute",
+        }
+    }
+}
+
+impl std::fmt::Display for WsComponentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.tag())
+    }
 }
 
 #[derive(Error, Debug)]
 pub enum WsErrorType {
     #[error("The input WSDL document was malformed: {0}")]
     MalformedWsdl(WsErrorMalformedType),
-    #[error("Attempt to refer to unknown element {0}")]
+    #[error("Reference \"{0}\" is not a valid qualified name")]
     InvalidReference(String),
+    #[error("Prefix \"{prefix}\" of {kind} reference \"{reference}\" is not declared")]
+    UnresolvedPrefix {
+        prefix: String,
+        reference: String,
+        kind: WsComponentKind,
+    },
+    #[error("No definitions of namespace \"{}\" are in scope to define {kind} {name}", .name.namespace.as_deref().unwrap_or_default())]
+    UnknownNamespace { name: QName, kind: WsComponentKind },
+    #[error("Attempt to refer to unknown {kind} {name}")]
+    UnknownComponent { name: QName, kind: WsComponentKind },
+    #[error("The {kind} {name} is defined more than once")]
+    DuplicateDefinition { name: QName, kind: WsComponentKind },
+    #[error("Attempt to refer to {name} as a {expected}, but it is a {found}")]
+    WrongElementKind {
+        name: QName,
+        expected: WsComponentKind,
+        found: WsComponentKind,
+    },
+    #[error("Unsupported WSDL version: the document element is {0}")]
+    UnsupportedWsdlVersion(QName),
+    /// The namespace of the name is imported, but the import was not loaded,
+    /// for instance because the definitions were not read as a [WsdlSet].
+    #[error("The {kind} {name} is defined by an import that was not loaded")]
+    ImportFailed {
+        name: QName,
+        kind: WsComponentKind,
+        /// The location the import was declared with, if any.
+        location: Option<String>,
+    },
+    #[error("The {kind} {name} is derived from itself")]
+    CircularDefinition { name: QName, kind: WsComponentKind },
+    /// An `xs:import`, `xs:include` or `xs:redefine` whose schema was not
+    /// loaded. The reference is the location of the schema, or the namespace
+    /// of an import without one.
+    #[error("The schema \"{reference}\" of an xs:{directive} was not loaded")]
+    UnresolvedSchema {
+        directive: xsd::SchemaDirective,
+        reference: String,
+    },
+    #[error("The message {message} has no part named \"{part}\"")]
+    UndeclaredPart { message: QName, part: String },
     #[error("Node unexpectedly did not have a parent node")]
     NoParentNode,
+    #[error("No binding for {0} is offered")]
+    UnsupportedSoapVersion(SoapVersion),
+    #[error("Failed to decode extension element: {0}")]
+    ExtensionDecode(WsExtensionError),
+    #[error("Violates WS-I Basic Profile {0}: {}", .0.requirement())]
+    ProfileViolation(BpAssertion),
 }
 
+/// An error raised while reading a WSDL document, along with the node that
+/// caused it. Build one with [WsError::new] from the offending node.
 #[derive(Error, Debug)]
-pub struct WsError(pub NodeId, pub WsErrorType);
+pub struct WsError(NodeId, WsErrorType, WsErrorOrigin);
+
+// Where in the source text an error was raised. This is captured up front,
+// as the error does not keep the document alive.
+#[derive(Debug, Clone)]
+struct WsErrorOrigin {
+    // The address of the document's text, which identifies it within a set.
+    document: usize,
+    position: TextPos,
+    span: Range<usize>,
+}
 
 impl WsError {
-    fn new(node: Node, typ: WsErrorType) -> Self {
-        Self(node.id(), typ)
+    /// Raise an error of the given type from a node. This captures the position
+    /// of the node, which takes time proportional to the size of the document,
+    /// so only construct errors that are actually returned.
+    pub fn new(node: Node, typ: WsErrorType) -> Self {
+        let document = node.document();
+        let span = start_tag(document.input_text(), node.range());
+
+        Self(
+            node.id(),
+            typ,
+            WsErrorOrigin {
+                document: document.input_text().as_ptr() as usize,
+                position: document.text_pos_at(span.start),
+                span,
+            },
+        )
+    }
+
+    /// Retrieve the ID of the node that caused the error.
+    pub fn node_id(&self) -> NodeId {
+        self.0
+    }
+
+    /// Retrieve what went wrong.
+    pub fn error_type(&self) -> &WsErrorType {
+        &self.1
+    }
+
+    /// Retrieve the line and column of the node that caused the error, within
+    /// the document it belongs to.
+    pub fn position(&self) -> TextPos {
+        self.2.position
+    }
+
+    /// Retrieve the byte range of the node that caused the error, within the
+    /// text of the document it belongs to. For elements this spans the start tag.
+    pub fn span(&self) -> Range<usize> {
+        self.2.span.clone()
+    }
+
+    /// Retrieve the location of the error, given the name of the file the
+    /// erroneous document was read from.
+    pub fn locate<'f>(&self, file: &'f str) -> WsLocation<'f> {
+        WsLocation {
+            file,
+            line: self.2.position.row,
+            column: self.2.position.col,
+        }
+    }
+
+    // Determine whether the error was raised from a node of the given document.
+    pub(crate) fn is_from(&self, document: &Document) -> bool {
+        self.2.document == document.input_text().as_ptr() as usize
+    }
+}
+
+#[cfg(feature = "codespan")]
+impl WsError {
+    /// Convert the error into a diagnostic, which `codespan-reporting` renders
+    /// as an annotated snippet of the file with the given ID.
+    pub fn to_diagnostic<FileId>(
+        &self,
+        file_id: FileId,
+    ) -> codespan_reporting::diagnostic::Diagnostic<FileId> {
+        use codespan_reporting::diagnostic::{Diagnostic, Label};
+
+        Diagnostic::error()
+            .with_message(self.to_string())
+            .with_labels(vec![Label::primary(file_id, self.span())])
     }
 }
 
@@ -36,7 +233,42 @@ impl std::fmt::Display for WsError {
     }
 }
 
-fn target_namespace<'a, 'input>(node: Node<'a, 'input>) -> Result<'a, 'input, &'a str> {
+/// A location in a WSDL file. This displays as `file:line:column`, which
+/// editors and terminals commonly recognize.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WsLocation<'f> {
+    pub file: &'f str,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl std::fmt::Display for WsLocation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+// Narrow the range of an element down to its start tag, so diagnostics don't
+// span entire subtrees. Other nodes are left untouched.
+fn start_tag(text: &str, range: Range<usize>) -> Range<usize> {
+    if !text[range.clone()].starts_with('<') {
+        return range;
+    }
+
+    let mut quote = None;
+    for (i, c) in text[range.clone()].char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return range.start..range.start + i + 1,
+            _ => {}
+        }
+    }
+
+    range
+}
+
+fn target_namespace<'a, 'input>(node: Node<'a, 'input>) -> Result<&'a str> {
     // Traverse the parents until we find the targetNamespace attribute.
     let mut nparent = node.parent();
     while let Some(parent) = nparent {
@@ -55,84 +287,236 @@ fn target_namespace<'a, 'input>(node: Node<'a, 'input>) -> Result<'a, 'input, &'
     ))
 }
 
-fn resolve_qualified<'a, 'input: 'a>(
+// Raise the error for a node that is not the expected root element of a WSDL
+// document. Root elements of other WSDL versions are reported as such.
+pub(crate) fn missing_root(node: Node, expected: &str) -> WsError {
+    let typ = if matches!(node.tag_name().name(), "definitions" | "description") {
+        WsErrorType::UnsupportedWsdlVersion(node.tag_name().into())
+    } else {
+        WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingElement(expected.to_string()))
+    };
+
+    WsError::new(node, typ)
+}
+
+// Retrieve an attribute that the WSDL schema requires to be present.
+pub(crate) fn required_attribute<'a>(node: Node<'a, '_>, name: &str) -> Result<&'a str> {
+    node.attribute(name).ok_or_else(|| {
+        WsError::new(
+            node,
+            WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(name.to_string())),
+        )
+    })
+}
+
+pub(crate) fn invalid_attribute(node: Node, name: &str) -> WsError {
+    WsError::new(
+        node,
+        WsErrorType::MalformedWsdl(WsErrorMalformedType::InvalidAttribute(name.to_string())),
+    )
+}
+
+pub(crate) fn resolve_qualified<'a, 'input: 'a>(
     node: Node<'a, 'input>,
     qualified_name: &'a str,
+    kind: WsComponentKind,
 ) -> std::result::Result<ExpandedName<'a, 'a>, WsErrorType> {
-    if qualified_name.contains(":") {
-        let mut s = qualified_name.split(":");
+    // Unprefixed names belong to the default namespace in scope, if any.
+    let (prefix, name) = match qualified_name.split_once(':') {
+        Some((prefix, name)) => (Some(prefix), name),
+        None => (None, qualified_name),
+    };
+
+    if name.is_empty() || name.contains(':') {
+        return Err(WsErrorType::InvalidReference(qualified_name.to_string()));
+    }
 
-        let ns = s
-            .next()
-            .ok_or(WsErrorType::InvalidReference(qualified_name.to_string()))?;
+    match (prefix, node.lookup_namespace_uri(prefix)) {
+        (_, Some(uri)) => Ok((uri, name).into()),
+        (None, None) => Ok(name.into()),
+        (Some(prefix), None) => Err(WsErrorType::UnresolvedPrefix {
+            prefix: prefix.to_string(),
+            reference: qualified_name.to_string(),
+            kind,
+        }),
+    }
+}
 
-        let uri = node
-            .lookup_namespace_uri(Some(ns))
-            .ok_or(WsErrorType::InvalidReference(qualified_name.to_string()))?;
+// Determine whether a node belongs to the WSDL 1.1 or 2.0 namespace.
+fn is_wsdl(node: Node) -> bool {
+    matches!(
+        node.tag_name().namespace(),
+        Some("http://schemas.xmlsoap.org/wsdl/" | "http://www.w3.org/ns/wsdl")
+    )
+}
 
-        let name = s
-            .next()
-            .ok_or(WsErrorType::InvalidReference(qualified_name.to_string()))?;
+// Retrieve the child elements of a node that belong to a namespace other than WSDL's.
+pub(crate) fn extension_elements<'a, 'input>(
+    node: Node<'a, 'input>,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children().filter(|n| n.is_element() && !is_wsdl(*n))
+}
 
-        Ok((uri, name).into())
+// Retrieve the text of a node's `wsdl:documentation`, with whitespace collapsed.
+// The documentation may contain markup, whose text is included.
+pub(crate) fn documentation(node: Node) -> Option<String> {
+    let text = node
+        .children()
+        .filter(|n| is_wsdl(*n) && n.tag_name().name() == "documentation")
+        .flat_map(|n| n.descendants())
+        .filter(|n| n.is_text())
+        .filter_map(|n| n.text())
+        .collect::<String>();
+
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        None
     } else {
-        Ok(qualified_name.into())
+        Some(text)
     }
 }
 
-fn split_qualified(qualified_name: &str) -> std::result::Result<(Option<&str>, &str), WsErrorType> {
-    let (namespace, name) = {
-        if qualified_name.contains(":") {
-            let mut s = qualified_name.split(":");
-            let ns = s
-                .next()
-                .ok_or(WsErrorType::InvalidReference(qualified_name.to_string()))?;
+/// Determines where qualified references made by a wrapper are looked up.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Scope<'a, 'input> {
+    /// Only the definitions enclosing the referring node are searched.
+    Document,
+    /// Every document of a [WsdlSet] is searched.
+    Set(&'a WsdlSet<'input>),
+    /// References are looked up in a prebuilt [WsIndex].
+    Index(&'a WsIndex<'a, 'input>),
+}
 
-            let name = s
-                .next()
-                .ok_or(WsErrorType::InvalidReference(qualified_name.to_string()))?;
+// Given a qualified name such as `tns:MyAnnoyingXmlType`, look for a WSDL
+// definition with both the name and element type. The name only matches
+// definitions whose `targetNamespace` is the namespace the prefix resolves to.
+pub(crate) fn lookup_qualified<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    scope: Scope<'a, 'input>,
+    qualified_name: &'a str,
+    kind: WsComponentKind,
+) -> Result<Node<'a, 'input>> {
+    let name = resolve_qualified(node, qualified_name, kind).map_err(|e| WsError::new(node, e))?;
+    let error = |typ| Err(WsError::new(node, typ));
+    let definitions = match scope {
+        Scope::Document => vec![WsDefinitions::find_parent(node)?],
+        Scope::Set(set) => set.definitions().collect(),
+        // Only scan the documents to explain why the index has no match.
+        Scope::Index(index) => match index.find(kind, name) {
+            [found] => return Ok(*found),
+            [] => index.definitions().collect(),
+            _ => {
+                return error(WsErrorType::DuplicateDefinition {
+                    name: name.into(),
+                    kind,
+                })
+            }
+        },
+    };
 
-            (Some(ns), name)
-        } else {
-            (None, qualified_name)
+    // Every top-level WSDL element with the name, regardless of its kind.
+    let named = definitions
+        .iter()
+        .filter(|d| d.target_namespace() == name.namespace())
+        .flat_map(|d| d.0.children())
+        .filter(|n| {
+            n.tag_name().namespace() == Some("http://schemas.xmlsoap.org/wsdl/")
+                && n.attribute("name") == Some(name.name())
+        });
+
+    let mut found = named.clone().filter(|n| n.tag_name().name() == kind.tag());
+    match (found.next(), found.next()) {
+        (Some(found), None) => return Ok(found),
+        (Some(_), Some(_)) => {
+            return error(WsErrorType::DuplicateDefinition {
+                name: name.into(),
+                kind,
+            })
         }
-    };
+        (None, _) => {}
+    }
 
-    Ok((namespace, name))
-}
+    // Work out why nothing matched, starting with the namespace.
+    let namespace = name.namespace().unwrap_or_default();
+    if !definitions
+        .iter()
+        .any(|d| d.target_namespace() == name.namespace())
+    {
+        let import = definitions
+            .iter()
+            .filter_map(|d| d.imports().ok())
+            .flatten()
+            .find(|i| i.namespace().ok() == Some(namespace));
+
+        return match import {
+            Some(import) => error(WsErrorType::ImportFailed {
+                name: name.into(),
+                kind,
+                location: import.location().map(str::to_string),
+            }),
+            None => error(WsErrorType::UnknownNamespace {
+                name: name.into(),
+                kind,
+            }),
+        };
+    }
 
-// Given a qualified name such as `tns:MyAnnoyingXmlType`, look for an XML
-// node with both the name and element type.
-/*
-fn lookup_qualified<'a, 'input>(
-    root: Node<'a, 'input>,
-    name: &str,
-    tag: &str,
-) -> Option<Node<'a, 'input>> {
-    todo!()
+    let other = named.clone().find_map(|n| {
+        WsComponentKind::DEFINITIONS
+            .into_iter()
+            .find(|k| k.tag() == n.tag_name().name())
+    });
+
+    match other {
+        Some(found) => error(WsErrorType::WrongElementKind {
+            name: name.into(),
+            expected: kind,
+            found,
+        }),
+        None => error(WsErrorType::UnknownComponent {
+            name: name.into(),
+            kind,
+        }),
+    }
 }
-*/
 
 /// Describes a WSDL `message`. These can otherwise be described as
 /// a list of function parameters.
 #[derive(Debug, Clone)]
-pub struct WsMessage<'a, 'input>(Node<'a, 'input>);
+pub struct WsMessage<'a, 'input>(pub(crate) Node<'a, 'input>, pub(crate) Scope<'a, 'input>);
 
 impl<'a, 'input> WsMessage<'a, 'input> {
     /// Retrieve the name of the message.
     pub fn name(&self) -> Result<&'a str> {
-        self.0.attribute("name").ok_or(WsError::new(
-            self.0,
-            WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute("name".to_string())),
-        ))
+        self.0.attribute("name").ok_or_else(|| {
+            WsError::new(
+                self.0,
+                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
+                    "name".to_string(),
+                )),
+            )
+        })
     }
 
     /// Retrieve the parts of this message.
-    pub fn parts(&self) -> impl Iterator<Item = WsMessagePart> {
+    pub fn parts(&self) -> impl Iterator<Item = WsMessagePart<'a, 'input>> {
+        let scope = self.1;
         self.0
             .children()
             .filter(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "part")))
-            .map(|n| WsMessagePart(n))
+            .map(move |n| WsMessagePart(n, scope))
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
     }
 
     /// Return the XML node this struct is associated with
@@ -141,35 +525,130 @@ impl<'a, 'input> WsMessage<'a, 'input> {
     }
 }
 
+/// Whether a [WsMessagePart] refers to a schema element or a schema type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum WsPartKind {
+    /// The part is a global element declaration, given by the `element` attribute.
+    Element,
+    /// The part is of a type, given by the `type` attribute.
+    Type,
+}
+
+impl WsPartKind {
+    /// Retrieve the name of the attribute of the part that refers to the component.
+    pub fn attribute(&self) -> &'static str {
+        match self {
+            Self::Element => "element",
+            Self::Type => "type",
+        }
+    }
+
+    fn component(&self) -> WsComponentKind {
+        match self {
+            Self::Element => WsComponentKind::SchemaElement,
+            Self::Type => WsComponentKind::SchemaType,
+        }
+    }
+}
+
+/// The schema component a [WsMessagePart] refers to.
+#[derive(Debug, Clone)]
+pub enum WsPartDefinition<'a, 'input> {
+    /// A global element declaration.
+    Element(xsd::Element<'a, 'input>),
+    /// A simple or complex type definition.
+    Type(xsd::Type<'a, 'input>),
+    /// A type built into XML Schema, which no schema defines.
+    Builtin(xsd::BuiltinType),
+}
+
 /// Describes a part of a WSDL message. This can otherwise be described
 /// as an individual function parameter.
 #[derive(Debug, Clone)]
-pub struct WsMessagePart<'a, 'input>(Node<'a, 'input>);
+pub struct WsMessagePart<'a, 'input>(pub(crate) Node<'a, 'input>, pub(crate) Scope<'a, 'input>);
 
 impl<'a, 'input: 'a> WsMessagePart<'a, 'input> {
     /// Retrieve the name of the part.
     pub fn name(&self) -> Result<&'a str> {
-        self.0.attribute("name").ok_or(WsError::new(
-            self.0,
-            WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute("name".to_string())),
-        ))
+        self.0.attribute("name").ok_or_else(|| {
+            WsError::new(
+                self.0,
+                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
+                    "name".to_string(),
+                )),
+            )
+        })
     }
 
-    /// Retrieve the typename of this parameter. This refers to a type defined
-    /// under the `wsdl:types` XML node.
-    pub fn typename(&self) -> Result<ExpandedName<'a, 'a>> {
-        let typename = self
-            .0
-            .attribute("element")
-            .or(self.0.attribute("type"))
-            .ok_or(WsError::new(
+    /// Retrieve whether the part refers to a schema element or a schema type.
+    /// A part should not refer to both, but if it does, the element is used.
+    pub fn kind(&self) -> Result<WsPartKind> {
+        match (self.0.attribute("element"), self.0.attribute("type")) {
+            (Some(_), _) => Ok(WsPartKind::Element),
+            (None, Some(_)) => Ok(WsPartKind::Type),
+            (None, None) => Err(WsError::new(
                 self.0,
                 WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
                     "type".to_string(),
                 )),
-            ))?;
+            )),
+        }
+    }
+
+    /// Retrieve the typename of this parameter. This refers to a type defined
+    /// under the `wsdl:types` XML node. See [Self::kind] for whether it names
+    /// an element or a type.
+    pub fn typename(&self) -> Result<ExpandedName<'a, 'a>> {
+        let kind = self.kind()?;
+        let typename = required_attribute(self.0, kind.attribute())?;
+
+        resolve_qualified(self.0, typename, kind.component()).map_err(|e| WsError::new(self.0, e))
+    }
+
+    /// Look up the schema element or type the part refers to, in the schemas
+    /// of every `wsdl:types` in scope. Types of the XML Schema namespace
+    /// resolve to a [BuiltinType](xsd::BuiltinType).
+    ///
+    /// Unless the part was obtained from a [WsIndex], the schemas are gathered
+    /// anew on every call. To resolve many parts, build an index first, or
+    /// look their names up in [xsd::Schemas] directly.
+    pub fn resolve(&self) -> Result<WsPartDefinition<'a, 'input>> {
+        let kind = self.kind()?;
+        let name = self.typename()?;
+
+        if kind == WsPartKind::Type && name.namespace() == Some(xsd::XSD_NS) {
+            if let Some(builtin) = xsd::BuiltinType::from_name(name.name()) {
+                return Ok(WsPartDefinition::Builtin(builtin));
+            }
+        }
+
+        xsd::Schemas::in_scope(self.0, self.1, |schemas| match kind {
+            WsPartKind::Element => schemas.element(name).map(WsPartDefinition::Element),
+            WsPartKind::Type => schemas.type_definition(name).map(WsPartDefinition::Type),
+        })?
+        .ok_or_else(|| {
+            WsError::new(
+                self.0,
+                WsErrorType::UnknownComponent {
+                    name: name.into(),
+                    kind: kind.component(),
+                },
+            )
+        })
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
 
-        resolve_qualified(self.0, typename).map_err(|e| WsError::new(self.0, e))
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
     }
 
     /// Return the XML node this struct is associated with
@@ -180,15 +659,19 @@ impl<'a, 'input: 'a> WsMessagePart<'a, 'input> {
 
 /// Describes a WSDL `portType`. These describe groups of operations.
 #[derive(Debug, Clone)]
-pub struct WsPortType<'a, 'input>(Node<'a, 'input>);
+pub struct WsPortType<'a, 'input>(pub(crate) Node<'a, 'input>, pub(crate) Scope<'a, 'input>);
 
 impl<'a, 'input> WsPortType<'a, 'input> {
     /// Retrieve the name of the port type.
     pub fn name(&self) -> Result<&'a str> {
-        self.0.attribute("name").ok_or(WsError::new(
-            self.0,
-            WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute("name".to_string())),
-        ))
+        self.0.attribute("name").ok_or_else(|| {
+            WsError::new(
+                self.0,
+                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
+                    "name".to_string(),
+                )),
+            )
+        })
     }
 
     /// Retrieve the port type's target namespace.
@@ -198,11 +681,24 @@ impl<'a, 'input> WsPortType<'a, 'input> {
 
     /// Retrieve the operations associated with this port.
     pub fn operations(&self) -> Result<impl Iterator<Item = WsPortOperation<'a, 'input>>> {
+        let scope = self.1;
         Ok(self
             .0
             .children()
             .filter(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "operation")))
-            .map(|n| WsPortOperation(n)))
+            .map(move |n| WsPortOperation(n, scope)))
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
     }
 
     /// Return the XML node this struct is associated with
@@ -214,96 +710,174 @@ impl<'a, 'input> WsPortType<'a, 'input> {
 /// Describes an operation associated with a WSDL `portType`.
 /// A WSDL operation can otherwise be described as a function.
 #[derive(Debug, Clone)]
-pub struct WsPortOperation<'a, 'input>(Node<'a, 'input>);
+pub struct WsPortOperation<'a, 'input>(pub(crate) Node<'a, 'input>, pub(crate) Scope<'a, 'input>);
 
 impl<'a, 'input> WsPortOperation<'a, 'input> {
     /// Retrieve the name of an operation.
     pub fn name(&self) -> Result<&'a str> {
-        self.0.attribute("name").ok_or(WsError::new(
-            self.0,
-            WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute("name".to_string())),
-        ))
+        self.0.attribute("name").ok_or_else(|| {
+            WsError::new(
+                self.0,
+                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
+                    "name".to_string(),
+                )),
+            )
+        })
     }
 
-    /// Retrieve the input message for this port.
-    pub fn input(&self) -> Result<Option<WsMessage<'a, 'input>>> {
-        let message_typename = match self
+    /// Resolve the message referenced by the first child element with the given tag.
+    fn message(&self, tag: &str) -> Result<Option<WsMessage<'a, 'input>>> {
+        let (node, message_typename) = match self
             .0
             .children()
-            .find(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "input")))
-            .map(|n| n.attribute("message"))
-            .flatten()
+            .find(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", tag)))
+            .and_then(|n| Some((n, n.attribute("message")?)))
         {
             Some(n) => n,
             None => return Ok(None),
         };
 
-        let (_message_namespace, message_name) =
-            split_qualified(message_typename).map_err(|e| WsError::new(self.0, e))?;
-
-        let def = WsDefinitions::find_parent(self.0)?;
-        Ok(Some(
-            def.messages()?
-                .find(|n| n.0.attribute("name") == Some(message_name))
-                .ok_or(WsError::new(
-                    self.0,
-                    WsErrorType::InvalidReference(message_name.to_string()),
-                ))?,
-        ))
+        Ok(Some(WsMessage(
+            lookup_qualified(node, self.1, message_typename, WsComponentKind::Message)?,
+            self.1,
+        )))
+    }
+
+    /// Retrieve the input message for this port.
+    pub fn input(&self) -> Result<Option<WsMessage<'a, 'input>>> {
+        self.message("input")
     }
 
     /// Retrieve the output message for this port.
     pub fn output(&self) -> Result<Option<WsMessage<'a, 'input>>> {
-        let message_typename = match self
-            .0
-            .children()
-            .find(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "output")))
-            .map(|n| n.attribute("message"))
-            .flatten()
-        {
-            Some(n) => n,
-            None => return Ok(None),
-        };
-
-        let (_message_namespace, message_name) =
-            split_qualified(message_typename).map_err(|e| WsError::new(self.0, e))?;
-
-        let def = WsDefinitions::find_parent(self.0)?;
-        Ok(Some(
-            def.messages()?
-                .find(|n| n.0.attribute("name") == Some(message_name))
-                .ok_or(WsError::new(
-                    self.0,
-                    WsErrorType::InvalidReference(message_name.to_string()),
-                ))?,
-        ))
+        self.message("output")
     }
 
-    /// Retrieve the fault message for this port.
+    /// Retrieve the fault message for this port. If the operation declares
+    /// several faults, only the first is returned; see [Self::faults].
     pub fn fault(&self) -> Result<Option<WsMessage<'a, 'input>>> {
-        let message_typename = match self
+        self.message("fault")
+    }
+
+    /// Classify the operation by the order of its `input` and `output` elements.
+    pub fn kind(&self) -> Result<OperationKind> {
+        let mut messages = self.0.children().filter(|n| {
+            n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "input"))
+                || n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "output"))
+        });
+
+        let first = messages.next().map(|n| n.tag_name().name());
+        let second = messages.next().map(|n| n.tag_name().name());
+
+        match (first, second) {
+            (Some("input"), None) => Ok(OperationKind::OneWay),
+            (Some("input"), Some("output")) => Ok(OperationKind::RequestResponse),
+            (Some("output"), Some("input")) => Ok(OperationKind::SolicitResponse),
+            (Some("output"), None) => Ok(OperationKind::Notification),
+            _ => Err(WsError::new(
+                self.0,
+                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingElement(
+                    "input".to_string(),
+                )),
+            )),
+        }
+    }
+
+    /// Retrieve the names of the parts listed by the `parameterOrder` attribute,
+    /// which gives the order of the parameters of an RPC-like signature.
+    pub fn parameter_order(&self) -> Option<Vec<&'a str>> {
+        self.0
+            .attribute("parameterOrder")
+            .map(|order| order.split_whitespace().collect())
+    }
+
+    /// Retrieve every fault this operation may return.
+    pub fn faults(&self) -> Result<impl Iterator<Item = WsOperationFault<'a, 'input>>> {
+        let scope = self.1;
+        Ok(self
             .0
             .children()
-            .find(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "fault")))
-            .map(|n| n.attribute("message"))
-            .flatten()
-        {
-            Some(n) => n,
-            None => return Ok(None),
-        };
+            .filter(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "fault")))
+            .map(move |n| WsOperationFault(n, scope)))
+    }
 
-        let (_message_namespace, message_name) =
-            split_qualified(message_typename).map_err(|e| WsError::new(self.0, e))?;
-
-        let def = WsDefinitions::find_parent(self.0)?;
-        Ok(Some(
-            def.messages()?
-                .find(|n| n.0.attribute("name") == Some(message_name))
-                .ok_or(WsError::new(
-                    self.0,
-                    WsErrorType::InvalidReference(message_name.to_string()),
-                ))?,
-        ))
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// The transmission primitive of a [WsPortOperation], as defined in
+/// section 2.4 of the WSDL 1.1 specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum OperationKind {
+    /// The endpoint receives a message.
+    OneWay,
+    /// The endpoint receives a message, and sends a correlated message.
+    RequestResponse,
+    /// The endpoint sends a message, and receives a correlated message.
+    SolicitResponse,
+    /// The endpoint sends a message.
+    Notification,
+}
+
+/// Describes a named fault of a [WsPortOperation]. These can otherwise be
+/// described as an error the function may return.
+#[derive(Debug, Clone)]
+pub struct WsOperationFault<'a, 'input>(pub(crate) Node<'a, 'input>, pub(crate) Scope<'a, 'input>);
+
+impl<'a, 'input> WsOperationFault<'a, 'input> {
+    /// Retrieve the name of the fault.
+    pub fn name(&self) -> Result<&'a str> {
+        self.0.attribute("name").ok_or_else(|| {
+            WsError::new(
+                self.0,
+                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
+                    "name".to_string(),
+                )),
+            )
+        })
+    }
+
+    /// Retrieve the message that describes the contents of the fault.
+    pub fn message(&self) -> Result<WsMessage<'a, 'input>> {
+        let message_typename = self.0.attribute("message").ok_or_else(|| {
+            WsError::new(
+                self.0,
+                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
+                    "message".to_string(),
+                )),
+            )
+        })?;
+
+        lookup_qualified(self.0, self.1, message_typename, WsComponentKind::Message)
+            .map(|n| WsMessage(n, self.1))
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
     }
 
     /// Return the XML node this struct is associated with
@@ -314,15 +888,22 @@ impl<'a, 'input> WsPortOperation<'a, 'input> {
 
 /// A WSDL binding operation.
 #[derive(Debug, Clone)]
-pub struct WsBindingOperation<'a, 'input>(Node<'a, 'input>);
+pub struct WsBindingOperation<'a, 'input>(
+    pub(crate) Node<'a, 'input>,
+    pub(crate) Scope<'a, 'input>,
+);
 
 impl<'a, 'input> WsBindingOperation<'a, 'input> {
     /// Return the name of the operation described.
     pub fn name(&self) -> Result<&'a str> {
-        self.0.attribute("name").ok_or(WsError::new(
-            self.0,
-            WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute("name".to_string())),
-        ))
+        self.0.attribute("name").ok_or_else(|| {
+            WsError::new(
+                self.0,
+                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
+                    "name".to_string(),
+                )),
+            )
+        })
     }
 
     /// Retrieve the port operation that corresponds to this binding operation.
@@ -331,22 +912,105 @@ impl<'a, 'input> WsBindingOperation<'a, 'input> {
         let binding = WsBinding(
             self.0
                 .parent()
-                .ok_or(WsError::new(self.0, WsErrorType::NoParentNode))?,
+                .ok_or_else(|| WsError::new(self.0, WsErrorType::NoParentNode))?,
+            self.1,
         );
 
         let port_type: WsPortType<'a, 'input> = binding.port_type()?;
 
-        for op in port_type.operations()? {
-            if op.name()? == name {
-                return Ok(op);
+        if let Scope::Index(index) = self.1 {
+            let key = (port_type.target_namespace().ok(), port_type.name()?);
+            if let Some(op) = index.operation(key, name) {
+                return Ok(WsPortOperation(op, self.1));
+            }
+        } else {
+            for op in port_type.operations()? {
+                if op.name()? == name {
+                    return Ok(op);
+                }
             }
         }
+
         Err(WsError::new(
             self.0,
-            WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingElement(name.to_string())),
+            WsErrorType::UnknownComponent {
+                name: QName::within(port_type.target_namespace().ok(), name),
+                kind: WsComponentKind::Operation,
+            },
         ))
     }
 
+    /// Retrieve the binding of the operation's input message.
+    pub fn input(&self) -> Result<Option<WsBindingMessage<'a, 'input>>> {
+        Ok(self
+            .0
+            .children()
+            .find(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "input")))
+            .map(|n| WsBindingMessage(n, self.1)))
+    }
+
+    /// Retrieve the binding of the operation's output message.
+    pub fn output(&self) -> Result<Option<WsBindingMessage<'a, 'input>>> {
+        Ok(self
+            .0
+            .children()
+            .find(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "output")))
+            .map(|n| WsBindingMessage(n, self.1)))
+    }
+
+    /// Retrieve the bindings of the operation's faults.
+    pub fn faults(&self) -> Result<impl Iterator<Item = WsBindingMessage<'a, 'input>>> {
+        let scope = self.1;
+        Ok(self
+            .0
+            .children()
+            .filter(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "fault")))
+            .map(move |n| WsBindingMessage(n, scope)))
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// The `input`, `output` or `fault` of a [WsBindingOperation]. This carries
+/// the wire details of the message, such as `soap:body`, in its extensibility
+/// elements.
+#[derive(Debug, Clone)]
+pub struct WsBindingMessage<'a, 'input>(pub(crate) Node<'a, 'input>, pub(crate) Scope<'a, 'input>);
+
+impl<'a, 'input> WsBindingMessage<'a, 'input> {
+    /// Retrieve the name of the message. This is required for faults, and
+    /// optional otherwise.
+    pub fn name(&self) -> Option<&'a str> {
+        self.0.attribute("name")
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
+    }
+
     /// Return the XML node this struct is associated with
     pub fn node(&self) -> Node<'a, 'input> {
         self.0
@@ -356,41 +1020,54 @@ impl<'a, 'input> WsBindingOperation<'a, 'input> {
 /// A WSDL binding that describes how the operations in a port type
 /// are bound to/from the wire.
 #[derive(Debug, Clone)]
-pub struct WsBinding<'a, 'input>(Node<'a, 'input>);
+pub struct WsBinding<'a, 'input>(pub(crate) Node<'a, 'input>, pub(crate) Scope<'a, 'input>);
 
 impl<'a, 'input> WsBinding<'a, 'input> {
     /// Retrieve the name of a binding.
     pub fn name(&self) -> Result<&'a str> {
-        self.0.attribute("name").ok_or(WsError::new(
-            self.0,
-            WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute("name".to_string())),
-        ))
+        self.0.attribute("name").ok_or_else(|| {
+            WsError::new(
+                self.0,
+                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
+                    "name".to_string(),
+                )),
+            )
+        })
     }
 
     pub fn port_type(&self) -> Result<WsPortType<'a, 'input>> {
-        let port_typename = self.0.attribute("type").ok_or(WsError::new(
-            self.0,
-            WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute("type".to_string())),
-        ))?;
-
-        let (_port_namespace, port_name) =
-            split_qualified(port_typename).map_err(|e| WsError::new(self.0, e))?;
-
-        let def = WsDefinitions::find_parent(self.0)?;
-        def.port_types()?
-            .find(|n| n.0.attribute("name") == Some(port_name))
-            .ok_or(WsError::new(
+        let port_typename = self.0.attribute("type").ok_or_else(|| {
+            WsError::new(
                 self.0,
-                WsErrorType::InvalidReference(port_name.to_string()),
-            ))
+                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
+                    "type".to_string(),
+                )),
+            )
+        })?;
+
+        lookup_qualified(self.0, self.1, port_typename, WsComponentKind::PortType)
+            .map(|n| WsPortType(n, self.1))
     }
 
-    pub fn operations(&self) -> Result<impl Iterator<Item = WsBindingOperation>> {
+    pub fn operations(&self) -> Result<impl Iterator<Item = WsBindingOperation<'a, 'input>>> {
+        let scope = self.1;
         Ok(self
             .0
             .children()
             .filter(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "operation")))
-            .map(|n| WsBindingOperation(n)))
+            .map(move |n| WsBindingOperation(n, scope)))
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
     }
 
     /// Return the XML node this struct is associated with
@@ -400,35 +1077,45 @@ impl<'a, 'input> WsBinding<'a, 'input> {
 }
 
 #[derive(Debug, Clone)]
-pub struct WsServicePort<'a, 'input>(Node<'a, 'input>);
+pub struct WsServicePort<'a, 'input>(pub(crate) Node<'a, 'input>, pub(crate) Scope<'a, 'input>);
 
 impl<'a, 'input> WsServicePort<'a, 'input> {
     pub fn name(&self) -> Result<&'a str> {
-        self.0.attribute("name").ok_or(WsError::new(
-            self.0,
-            WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute("name".to_string())),
-        ))
+        self.0.attribute("name").ok_or_else(|| {
+            WsError::new(
+                self.0,
+                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
+                    "name".to_string(),
+                )),
+            )
+        })
     }
 
     /// Fetch the binding information associated with this service port.
     pub fn binding(&self) -> Result<WsBinding<'a, 'input>> {
-        let binding_typename = self.0.attribute("binding").ok_or(WsError::new(
-            self.0,
-            WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
-                "binding".to_string(),
-            )),
-        ))?;
+        let binding_typename = self.0.attribute("binding").ok_or_else(|| {
+            WsError::new(
+                self.0,
+                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
+                    "binding".to_string(),
+                )),
+            )
+        })?;
+
+        lookup_qualified(self.0, self.1, binding_typename, WsComponentKind::Binding)
+            .map(|n| WsBinding(n, self.1))
+    }
 
-        let (_binding_namespace, binding_name) =
-            split_qualified(binding_typename).map_err(|e| WsError::new(self.0, e))?;
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
 
-        let def = WsDefinitions::find_parent(self.0)?;
-        def.bindings()?
-            .find(|n| n.0.attribute("name") == Some(binding_name))
-            .ok_or(WsError::new(
-                self.0,
-                WsErrorType::InvalidReference(binding_name.to_string()),
-            ))
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
     }
 
     /// Return the XML node this struct is associated with
@@ -440,22 +1127,39 @@ impl<'a, 'input> WsServicePort<'a, 'input> {
 /// A WSDL service, usually describing an HTTP endpoint that serves
 /// messages bound with a [WsBinding]
 #[derive(Debug, Clone)]
-pub struct WsService<'a, 'input>(Node<'a, 'input>);
+pub struct WsService<'a, 'input>(pub(crate) Node<'a, 'input>, pub(crate) Scope<'a, 'input>);
 
 impl<'a, 'input> WsService<'a, 'input> {
     pub fn name(&self) -> Result<&'a str> {
-        self.0.attribute("name").ok_or(WsError::new(
-            self.0,
-            WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute("name".to_string())),
-        ))
+        self.0.attribute("name").ok_or_else(|| {
+            WsError::new(
+                self.0,
+                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
+                    "name".to_string(),
+                )),
+            )
+        })
     }
 
-    pub fn ports(&self) -> Result<impl Iterator<Item = WsServicePort>> {
+    pub fn ports(&self) -> Result<impl Iterator<Item = WsServicePort<'a, 'input>>> {
+        let scope = self.1;
         Ok(self
             .0
             .children()
             .filter(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "port")))
-            .map(|n| WsServicePort(n)))
+            .map(move |n| WsServicePort(n, scope)))
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
     }
 
     /// Return the XML node this struct is associated with
@@ -465,29 +1169,88 @@ impl<'a, 'input> WsService<'a, 'input> {
 }
 
 #[derive(Debug, Clone)]
-pub struct WsTypes<'a, 'input>(Node<'a, 'input>);
+pub struct WsTypes<'a, 'input>(pub(crate) Node<'a, 'input>);
 
 impl<'a, 'input> WsTypes<'a, 'input> {
-    /// Return the schemas contained within. These are defined according to the XML schema specification,
-    /// and are out of scope for this library to interpret.
-    pub fn schemas(&self) -> Result<impl Iterator<Item = Node<'a, 'input>>> {
+    /// Return the schemas contained within. See [xsd::Schemas] for looking up
+    /// their components by name.
+    pub fn schemas(&self) -> Result<impl Iterator<Item = xsd::Schema<'a, 'input>>> {
         Ok(self
             .0
             .children()
-            .filter(|n| n.has_tag_name(("http://www.w3.org/2001/XMLSchema", "schema"))))
+            .filter(|n| n.has_tag_name((xsd::XSD_NS, "schema")))
+            .map(xsd::Schema))
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// A WSDL `import`, which pulls the definitions of another namespace
+/// into scope. See [WsdlSet] for following these across documents.
+#[derive(Debug, Clone)]
+pub struct WsImport<'a, 'input>(pub(crate) Node<'a, 'input>);
+
+impl<'a, 'input> WsImport<'a, 'input> {
+    /// Retrieve the namespace of the imported definitions.
+    pub fn namespace(&self) -> Result<&'a str> {
+        self.0.attribute("namespace").ok_or_else(|| {
+            WsError::new(
+                self.0,
+                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingAttribute(
+                    "namespace".to_string(),
+                )),
+            )
+        })
+    }
+
+    /// Retrieve the location of the imported document, if specified.
+    pub fn location(&self) -> Option<&'a str> {
+        self.0.attribute("location")
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct WsDefinitions<'a, 'input>(Node<'a, 'input>);
+pub struct WsDefinitions<'a, 'input>(pub(crate) Node<'a, 'input>, pub(crate) Scope<'a, 'input>);
 
 impl<'a, 'input> WsDefinitions<'a, 'input> {
     /// Find the definitions block from one of the node's parents
-    fn find_parent(mut node: Node<'a, 'input>) -> Result<'a, 'input, Self> {
+    pub(crate) fn find_parent(mut node: Node<'a, 'input>) -> Result<Self> {
         loop {
             node = node
                 .parent()
-                .ok_or(WsError::new(node, WsErrorType::NoParentNode))?;
+                .ok_or_else(|| WsError::new(node, WsErrorType::NoParentNode))?;
 
             if let Ok(definitions) = Self::from_node(node) {
                 return Ok(definitions);
@@ -495,75 +1258,550 @@ impl<'a, 'input> WsDefinitions<'a, 'input> {
         }
     }
 
-    pub fn from_node(node: Node<'a, 'input>) -> Result<'a, 'input, Self> {
+    pub fn from_node(node: Node<'a, 'input>) -> Result<Self> {
         if node.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "definitions")) {
-            Ok(Self(node))
+            Ok(Self(node, Scope::Document))
         } else {
-            Err(WsError::new(
-                node,
-                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingElement(
-                    "definitions".to_string(),
-                )),
-            ))
+            Err(missing_root(node, "definitions"))
         }
     }
 
-    pub fn from_document(document: &'a Document<'input>) -> Result<'a, 'input, Self> {
+    pub fn from_document(document: &'a Document<'input>) -> Result<Self> {
         document
             .root()
             .children()
             .find(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "definitions")))
-            .ok_or(WsError::new(
-                document.root_element(),
-                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingElement(
-                    "definitions".to_string(),
-                )),
-            ))
-            .map(|n| Self(n))
+            .ok_or_else(|| missing_root(document.root_element(), "definitions"))
+            .map(|n| Self(n, Scope::Document))
+    }
+
+    /// Wrap a definitions node whose references resolve against the given scope.
+    pub(crate) fn with_scope(node: Node<'a, 'input>, scope: Scope<'a, 'input>) -> Result<Self> {
+        Self::from_node(node).map(|def| Self(def.0, scope))
+    }
+
+    /// Retrieve the target namespace of these definitions, if any.
+    pub fn target_namespace(&self) -> Option<&'a str> {
+        self.0.attribute("targetNamespace")
+    }
+
+    /// Retrieve the `wsdl:import` statements of these definitions.
+    pub fn imports(&self) -> Result<impl Iterator<Item = WsImport<'a, 'input>>> {
+        Ok(self
+            .0
+            .children()
+            .filter(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "import")))
+            .map(WsImport))
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
     }
 
     pub fn port_types(&self) -> Result<impl Iterator<Item = WsPortType<'a, 'input>>> {
+        let scope = self.1;
         Ok(self
             .0
             .children()
             .filter(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "portType")))
-            .map(|n| WsPortType(n))
-            .into_iter())
+            .map(move |n| WsPortType(n, scope)))
     }
 
     pub fn messages(&self) -> Result<impl Iterator<Item = WsMessage<'a, 'input>>> {
+        let scope = self.1;
         Ok(self
             .0
             .children()
             .filter(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "message")))
-            .map(|n| WsMessage(n))
-            .into_iter())
+            .map(move |n| WsMessage(n, scope)))
     }
 
     pub fn bindings(&self) -> Result<impl Iterator<Item = WsBinding<'a, 'input>>> {
+        let scope = self.1;
         Ok(self
             .0
             .children()
             .filter(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "binding")))
-            .map(|n| WsBinding(n))
-            .into_iter())
+            .map(move |n| WsBinding(n, scope)))
     }
 
     pub fn services(&self) -> Result<impl Iterator<Item = WsService<'a, 'input>>> {
+        let scope = self.1;
         Ok(self
             .0
             .children()
             .filter(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "service")))
-            .map(|n| WsService(n))
-            .into_iter())
+            .map(move |n| WsService(n, scope)))
     }
 
-    pub fn types(&self) -> Result<impl Iterator<Item = Node<'a, 'input>>> {
-        // FIXME: I'm pretty sure only one of these nodes can exist?
+    /// Retrieve the `wsdl:types` element, which holds the schemas describing
+    /// the contents of messages. There is at most one.
+    pub fn types(&self) -> Result<Option<WsTypes<'a, 'input>>> {
         Ok(self
             .0
             .children()
-            .filter(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "types")))
-            .into_iter())
+            .find(|n| n.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "types")))
+            .map(WsTypes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Messages named alike in the target namespace and in one that is only
+    // declared, referred to with prefixes, a default namespace and no prefix.
+    const REFERENCES: &str = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+            xmlns:wsdl="http://schemas.xmlsoap.org/wsdl/"
+            xmlns:a="urn:a" xmlns:b="urn:b" xmlns:i="urn:i" targetNamespace="urn:a">
+          <import namespace="urn:i" location="i.wsdl"/>
+          <message name="Request"/>
+          <message name="Response"/>
+          <message name="Twice"/>
+          <message name="Twice"/>
+          <portType name="Port">
+            <operation name="Qualified">
+              <input message="a:Request"/>
+              <output message="a:Response"/>
+            </operation>
+            <operation name="Default">
+              <wsdl:input xmlns="urn:a" message="Request"/>
+            </operation>
+            <operation name="Foreign">
+              <input message="b:Request"/>
+            </operation>
+            <operation name="Unbound">
+              <input message="c:Request"/>
+            </operation>
+            <operation name="Missing">
+              <input message="a:Missing"/>
+            </operation>
+            <operation name="Malformed">
+              <input message="a:b:c"/>
+            </operation>
+            <operation name="WrongKind">
+              <input message="a:Port"/>
+            </operation>
+            <operation name="Duplicate">
+              <input message="a:Twice"/>
+            </operation>
+            <operation name="Imported">
+              <input message="i:Request"/>
+            </operation>
+          </portType>
+          <binding name="Binding" type="a:Port"/>
+          <service name="Service">
+            <port name="Port" binding="a:Binding"/>
+          </service>
+        </definitions>"#;
+
+    fn operation<'a, 'input>(
+        definitions: &WsDefinitions<'a, 'input>,
+        name: &str,
+    ) -> WsPortOperation<'a, 'input> {
+        let port_type = definitions.port_types().unwrap().next().unwrap();
+        let operation = port_type
+            .operations()
+            .unwrap()
+            .find(|o| o.name().unwrap() == name);
+        operation.unwrap()
+    }
+
+    #[test]
+    fn resolve_references_by_expanded_name() {
+        let document = Document::parse(REFERENCES).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+
+        let qualified = operation(&definitions, "Qualified");
+        let input = qualified.input().unwrap().unwrap();
+        let output = qualified.output().unwrap().unwrap();
+        assert_eq!(input.name().unwrap(), "Request");
+        assert_eq!(output.name().unwrap(), "Response");
+
+        let default = operation(&definitions, "Default").input().unwrap().unwrap();
+        assert_eq!(default.node(), input.node());
+
+        let service = definitions.services().unwrap().next().unwrap();
+        let binding = service.ports().unwrap().next().unwrap().binding().unwrap();
+        assert_eq!(binding.name().unwrap(), "Binding");
+        assert_eq!(binding.port_type().unwrap().name().unwrap(), "Port");
+    }
+
+    #[test]
+    fn report_unresolved_references() {
+        let document = Document::parse(REFERENCES).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let error = |name| operation(&definitions, name).input().unwrap_err();
+        let qname = |namespace, name| QName {
+            namespace: Some(String::from(namespace)),
+            name: String::from(name),
+        };
+
+        // `b:Request` must not match the message of the same local name in the
+        // target namespace.
+        match error("Foreign").error_type() {
+            WsErrorType::UnknownNamespace { name, kind } => {
+                assert_eq!(name, &qname("urn:b", "Request"));
+                assert_eq!(*kind, WsComponentKind::Message);
+            }
+            other => panic!("unexpected error {other}"),
+        }
+        match error("Unbound").error_type() {
+            WsErrorType::UnresolvedPrefix {
+                prefix,
+                reference,
+                kind,
+            } => {
+                assert_eq!((prefix.as_str(), reference.as_str()), ("c", "c:Request"));
+                assert_eq!(*kind, WsComponentKind::Message);
+            }
+            other => panic!("unexpected error {other}"),
+        }
+        match error("Missing").error_type() {
+            WsErrorType::UnknownComponent { name, kind } => {
+                assert_eq!(name, &qname("urn:a", "Missing"));
+                assert_eq!(*kind, WsComponentKind::Message);
+            }
+            other => panic!("unexpected error {other}"),
+        }
+        match error("Malformed").error_type() {
+            WsErrorType::InvalidReference(reference) => assert_eq!(reference, "a:b:c"),
+            other => panic!("unexpected error {other}"),
+        }
+        match error("WrongKind").error_type() {
+            WsErrorType::WrongElementKind {
+                name,
+                expected,
+                found,
+            } => {
+                assert_eq!(name, &qname("urn:a", "Port"));
+                assert_eq!(*expected, WsComponentKind::Message);
+                assert_eq!(*found, WsComponentKind::PortType);
+            }
+            other => panic!("unexpected error {other}"),
+        }
+        match error("Duplicate").error_type() {
+            WsErrorType::DuplicateDefinition { name, kind } => {
+                assert_eq!(name, &qname("urn:a", "Twice"));
+                assert_eq!(*kind, WsComponentKind::Message);
+            }
+            other => panic!("unexpected error {other}"),
+        }
+
+        // The import is only followed when read as a set.
+        match error("Imported").error_type() {
+            WsErrorType::ImportFailed {
+                name,
+                kind,
+                location,
+            } => {
+                assert_eq!(name, &qname("urn:i", "Request"));
+                assert_eq!(*kind, WsComponentKind::Message);
+                assert_eq!(location.as_deref(), Some("i.wsdl"));
+            }
+            other => panic!("unexpected error {other}"),
+        }
+    }
+
+    #[test]
+    fn report_unsupported_wsdl_version() {
+        let text = include_str!("../assets/greath/reservation.wsdl");
+        let document = Document::parse(text).unwrap();
+        let error = WsDefinitions::from_document(&document).unwrap_err();
+        match error.error_type() {
+            WsErrorType::UnsupportedWsdlVersion(name) => {
+                assert_eq!(name.to_string(), "{http://www.w3.org/ns/wsdl}description")
+            }
+            other => panic!("unexpected error {other}"),
+        }
+
+        let document = Document::parse("<schema/>").unwrap();
+        let error = WsDefinitions::from_document(&document).unwrap_err();
+        assert!(matches!(
+            error.error_type(),
+            WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingElement(_))
+        ));
+    }
+
+    #[test]
+    fn resolve_references_across_documents() {
+        let service = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+                xmlns:a="urn:a" xmlns:b="urn:b" targetNamespace="urn:a">
+              <import namespace="urn:b" location="b.wsdl"/>
+              <message name="Request"><part name="a" type="a:A"/></message>
+              <portType name="Port">
+                <operation name="Echo">
+                  <input message="a:Request"/>
+                  <output message="b:Request"/>
+                </operation>
+              </portType>
+            </definitions>"#;
+        let other = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+                xmlns:b="urn:b" targetNamespace="urn:b">
+              <message name="Request"><part name="b" type="b:B"/></message>
+            </definitions>"#;
+
+        let mut resolver = crate::MemoryResolver::new();
+        resolver.insert("a.wsdl", service).insert("b.wsdl", other);
+        let sources = crate::WsdlSources::load(&resolver, "a.wsdl").unwrap();
+        let set = crate::WsdlSet::parse(&sources).unwrap();
+
+        let operation = operation(&set.root().unwrap(), "Echo");
+        let part = |message: Option<WsMessage>| {
+            let part = message.unwrap().parts().next().unwrap();
+            part.name().unwrap().to_string()
+        };
+        assert_eq!(part(operation.input().unwrap()), "a");
+        assert_eq!(part(operation.output().unwrap()), "b");
+    }
+
+    #[test]
+    fn list_every_fault() {
+        let text = include_str!("../assets/aic/workflow_wsdl.xml");
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+
+        let execute = operation(&definitions, "Execute");
+        let faults: Vec<_> = execute
+            .faults()
+            .unwrap()
+            .map(|f| f.name().unwrap())
+            .collect();
+        assert_eq!(faults, ["AicServiceFault"]);
+        assert_eq!(
+            execute.fault().unwrap().unwrap().name().unwrap(),
+            "AicServiceFault"
+        );
+
+        let text = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+                xmlns:a="urn:a" targetNamespace="urn:a">
+              <message name="First"/>
+              <message name="Second"/>
+              <portType name="Port">
+                <operation name="Echo">
+                  <fault name="first" message="a:First"/>
+                  <fault name="second" message="a:Second"/>
+                </operation>
+                <operation name="Quiet"/>
+              </portType>
+            </definitions>"#;
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+
+        let echo = operation(&definitions, "Echo");
+        let faults: Vec<_> = echo.faults().unwrap().map(|f| f.name().unwrap()).collect();
+        assert_eq!(faults, ["first", "second"]);
+        assert_eq!(echo.fault().unwrap().unwrap().name().unwrap(), "First");
+
+        let quiet = operation(&definitions, "Quiet");
+        assert_eq!(quiet.faults().unwrap().count(), 0);
+        assert!(quiet.fault().unwrap().is_none());
+    }
+
+    #[test]
+    fn classify_transmission_primitives() {
+        let text = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+                xmlns:a="urn:a" targetNamespace="urn:a">
+              <message name="Message"/>
+              <portType name="Port">
+                <operation name="OneWay">
+                  <input message="a:Message"/>
+                </operation>
+                <operation name="RequestResponse" parameterOrder="first  second">
+                  <input message="a:Message"/>
+                  <output message="a:Message"/>
+                  <fault name="fault" message="a:Message"/>
+                </operation>
+                <operation name="SolicitResponse">
+                  <output message="a:Message"/>
+                  <input message="a:Message"/>
+                </operation>
+                <operation name="Notification">
+                  <output message="a:Message"/>
+                </operation>
+                <operation name="Empty"/>
+                <operation name="Twice">
+                  <input message="a:Message"/>
+                  <input message="a:Message"/>
+                </operation>
+              </portType>
+            </definitions>"#;
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+
+        for (name, kind) in [
+            ("OneWay", OperationKind::OneWay),
+            ("RequestResponse", OperationKind::RequestResponse),
+            ("SolicitResponse", OperationKind::SolicitResponse),
+            ("Notification", OperationKind::Notification),
+        ] {
+            assert_eq!(operation(&definitions, name).kind().unwrap(), kind);
+        }
+        assert!(operation(&definitions, "Empty").kind().is_err());
+        assert!(operation(&definitions, "Twice").kind().is_err());
+
+        let order = operation(&definitions, "RequestResponse").parameter_order();
+        assert_eq!(order, Some(vec!["first", "second"]));
+        assert_eq!(operation(&definitions, "OneWay").parameter_order(), None);
+    }
+
+    #[test]
+    fn binding_messages() {
+        let text = include_str!("../assets/aic/workflow_wsdl.xml");
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+
+        let binding = definitions.bindings().unwrap().next().unwrap();
+        let execute = binding.operations().unwrap().next().unwrap();
+        assert_eq!(execute.port_operation().unwrap().name().unwrap(), "Execute");
+
+        let input = execute.input().unwrap().unwrap();
+        assert_eq!(input.name(), Some("ExecuteRequest"));
+        let extensions: Vec<_> = input.extensions().map(|e| e.name().name()).collect();
+        assert_eq!(extensions, ["body"]);
+
+        let output = execute.output().unwrap().unwrap();
+        assert_eq!(output.name(), Some("ExecuteResponse"));
+
+        let faults: Vec<_> = execute.faults().unwrap().collect();
+        assert_eq!(faults.len(), 1);
+        assert_eq!(faults[0].name(), Some("AicServiceFault"));
+        let extension = faults[0].extensions().next().unwrap();
+        assert_eq!(extension.name().name(), "fault");
+        assert_eq!(extension.attribute("name"), Some("AicServiceFault"));
+
+        // The `documentation` of a message is WSDL's own, not an extension.
+        let text = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+                xmlns:soap="http://schemas.xmlsoap.org/wsdl/soap/">
+              <binding name="Binding">
+                <operation name="Notify">
+                  <output>
+                    <documentation>Sent once.</documentation>
+                    <soap:body use="literal"/>
+                  </output>
+                </operation>
+              </binding>
+            </definitions>"#;
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+
+        let binding = definitions.bindings().unwrap().next().unwrap();
+        let notify = binding.operations().unwrap().next().unwrap();
+        assert!(notify.input().unwrap().is_none());
+        assert_eq!(notify.faults().unwrap().count(), 0);
+
+        let output = notify.output().unwrap().unwrap();
+        assert_eq!(output.name(), None);
+        let extensions: Vec<_> = output.extensions().map(|e| e.name().name()).collect();
+        assert_eq!(extensions, ["body"]);
+    }
+
+    #[test]
+    fn normalize_documentation() {
+        let text = r#"<definitions xmlns="http://schemas.xmlsoap.org/wsdl/"
+                xmlns:a="urn:a" targetNamespace="urn:a">
+              <documentation>
+                An   echo
+                service.
+              </documentation>
+              <message name="Request">
+                <documentation>The <b>only</b> message.</documentation>
+                <part name="body" type="a:Body">
+                  <documentation>   </documentation>
+                </part>
+              </message>
+              <portType name="Port">
+                <operation name="Echo">
+                  <documentation>Echo the <i>request</i>.</documentation>
+                  <input message="a:Request"/>
+                </operation>
+              </portType>
+            </definitions>"#;
+        let document = Document::parse(text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+
+        let documentation = definitions.documentation();
+        assert_eq!(documentation.as_deref(), Some("An echo service."));
+
+        let message = definitions.messages().unwrap().next().unwrap();
+        let documentation = message.documentation();
+        assert_eq!(documentation.as_deref(), Some("The only message."));
+
+        // Documentation with nothing but whitespace is absent.
+        let part = message.parts().next().unwrap();
+        assert_eq!(part.documentation(), None);
+
+        let port_type = definitions.port_types().unwrap().next().unwrap();
+        assert_eq!(port_type.documentation(), None);
+        let echo = operation(&definitions, "Echo");
+        assert_eq!(echo.documentation().as_deref(), Some("Echo the request."));
+    }
+
+    // An operation whose input refers to a message that does not exist.
+    const MISSING: &str = "<definitions xmlns=\"http://schemas.xmlsoap.org/wsdl/\"
+    xmlns:a=\"urn:a\" targetNamespace=\"urn:a\">
+  <portType name=\"Port\">
+    <operation name=\"Echo\"><input message=\"a:Missing\" name=\"x>y\"/></operation>
+  </portType>
+</definitions>";
+
+    #[test]
+    fn locate_errors_in_the_source() {
+        let document = Document::parse(MISSING).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let error = operation(&definitions, "Echo").input().unwrap_err();
+
+        assert_eq!(error.position(), TextPos::new(4, 28));
+        assert_eq!(
+            &MISSING[error.span()],
+            r#"<input message="a:Missing" name="x>y"/>"#
+        );
+        let node = document.get_node(error.node_id()).unwrap();
+        assert!(node.has_tag_name(("http://schemas.xmlsoap.org/wsdl/", "input")));
+        assert!(error.is_from(&document));
+
+        let location = error.locate("echo.wsdl");
+        assert_eq!(location.to_string(), "echo.wsdl:4:28");
+        assert_eq!((location.line, location.column), (4, 28));
+    }
+
+    #[cfg(feature = "codespan")]
+    #[test]
+    fn render_diagnostics() {
+        use codespan_reporting::files::SimpleFiles;
+        use codespan_reporting::term::{self, termcolor::NoColor};
+
+        let document = Document::parse(MISSING).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let error = operation(&definitions, "Echo").input().unwrap_err();
+
+        let mut files = SimpleFiles::new();
+        let file = files.add("echo.wsdl", MISSING);
+        let mut output = NoColor::new(Vec::new());
+        term::emit(
+            &mut output,
+            &term::Config::default(),
+            &files,
+            &error.to_diagnostic(file),
+        )
+        .unwrap();
+
+        let output = String::from_utf8(output.into_inner()).unwrap();
+        assert!(output.starts_with(&format!("error: {}", error)), "{output}");
+        assert!(output.contains("echo.wsdl:4:28"), "{output}");
+        assert!(output.contains(r#"<input message="a:Missing" name="x>y"/>"#));
     }
 }
@@ -0,0 +1,830 @@
+use roxmltree::{Document, Node};
+
+use crate::wsdl::{
+    documentation, extension_elements, invalid_attribute, missing_root, required_attribute,
+    resolve_qualified, Result, WsComponentKind, WsErrorType,
+};
+use crate::{WsError, WsExtension, WsProtocol, WsTypes};
+
+// The WSDL 2.0 namespace, as defined in the W3C recommendation.
+const WSDL2_NS: &str = "http://www.w3.org/ns/wsdl";
+// The namespace of the WSDL 2.0 SOAP binding extensions.
+const WSOAP_NS: &str = "http://www.w3.org/ns/wsdl/soap";
+// The namespace of the WSDL 2.0 HTTP binding extensions.
+const WHTTP_NS: &str = "http://www.w3.org/ns/wsdl/http";
+
+fn wsdl2_children<'a, 'input>(
+    node: Node<'a, 'input>,
+    tag: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |n| n.has_tag_name((WSDL2_NS, tag)))
+}
+
+// Given a qualified name, look for a top-level component of the enclosing
+// description with both the name and element type.
+fn lookup<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    qualified_name: &'a str,
+    kind: WsComponentKind,
+) -> Result<Node<'a, 'input>> {
+    let name = resolve_qualified(node, qualified_name, kind).map_err(|e| WsError::new(node, e))?;
+    let description = Ws2Description::find_parent(node)?;
+
+    if description.target_namespace().ok() != name.namespace() {
+        return Err(WsError::new(
+            node,
+            WsErrorType::UnknownNamespace {
+                name: name.into(),
+                kind,
+            },
+        ));
+    }
+
+    let mut found = wsdl2_children(description.0, kind.tag())
+        .filter(|n| n.attribute("name") == Some(name.name()));
+
+    match (found.next(), found.next()) {
+        (Some(found), None) => Ok(found),
+        (Some(_), Some(_)) => Err(WsError::new(
+            node,
+            WsErrorType::DuplicateDefinition {
+                name: name.into(),
+                kind,
+            },
+        )),
+        (None, _) => Err(WsError::new(
+            node,
+            WsErrorType::UnknownComponent {
+                name: name.into(),
+                kind,
+            },
+        )),
+    }
+}
+
+/// A message exchange pattern, which defines the sequence and cardinality
+/// of the messages of an operation. These are the patterns predefined by
+/// part 2 of the WSDL 2.0 specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageExchangePattern {
+    InOnly,
+    RobustInOnly,
+    InOut,
+    InOptionalOut,
+    OutOnly,
+    RobustOutOnly,
+    OutIn,
+    OutOptionalIn,
+}
+
+impl MessageExchangePattern {
+    const ALL: [Self; 8] = [
+        Self::InOnly,
+        Self::RobustInOnly,
+        Self::InOut,
+        Self::InOptionalOut,
+        Self::OutOnly,
+        Self::RobustOutOnly,
+        Self::OutIn,
+        Self::OutOptionalIn,
+    ];
+
+    /// Retrieve the URI that identifies the pattern.
+    pub fn uri(&self) -> &'static str {
+        match self {
+            Self::InOnly => "http://www.w3.org/ns/wsdl/in-only",
+            Self::RobustInOnly => "http://www.w3.org/ns/wsdl/robust-in-only",
+            Self::InOut => "http://www.w3.org/ns/wsdl/in-out",
+            Self::InOptionalOut => "http://www.w3.org/ns/wsdl/in-opt-out",
+            Self::OutOnly => "http://www.w3.org/ns/wsdl/out-only",
+            Self::RobustOutOnly => "http://www.w3.org/ns/wsdl/robust-out-only",
+            Self::OutIn => "http://www.w3.org/ns/wsdl/out-in",
+            Self::OutOptionalIn => "http://www.w3.org/ns/wsdl/out-opt-in",
+        }
+    }
+
+    /// Look up the pattern identified by a URI.
+    pub fn from_uri(uri: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.uri() == uri)
+    }
+}
+
+impl From<crate::OperationKind> for MessageExchangePattern {
+    fn from(kind: crate::OperationKind) -> Self {
+        match kind {
+            crate::OperationKind::OneWay => Self::InOnly,
+            crate::OperationKind::RequestResponse => Self::InOut,
+            crate::OperationKind::SolicitResponse => Self::OutIn,
+            crate::OperationKind::Notification => Self::OutOnly,
+        }
+    }
+}
+
+/// A message of a [Ws2InterfaceOperation]: an `input` or `output` element.
+#[derive(Debug, Clone)]
+pub struct Ws2MessageReference<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> Ws2MessageReference<'a, 'input> {
+    /// Retrieve the label of the message's role in the pattern, such as `In`
+    /// or `Out`. If unspecified, the pattern has a single message in this
+    /// direction.
+    pub fn message_label(&self) -> Option<&'a str> {
+        self.0.attribute("messageLabel")
+    }
+
+    /// Retrieve the content model of the message: the qualified name of a
+    /// schema element, or one of the tokens `#any`, `#none` and `#other`.
+    pub fn element(&self) -> Option<&'a str> {
+        self.0.attribute("element")
+    }
+
+    /// Determine whether the message is sent by the service, rather than received.
+    pub fn is_output(&self) -> bool {
+        self.0.has_tag_name((WSDL2_NS, "output"))
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// A fault of a [Ws2InterfaceOperation]: an `infault` or `outfault` element.
+#[derive(Debug, Clone)]
+pub struct Ws2FaultReference<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> Ws2FaultReference<'a, 'input> {
+    /// Retrieve the label of the message the fault is related to, if specified.
+    pub fn message_label(&self) -> Option<&'a str> {
+        self.0.attribute("messageLabel")
+    }
+
+    /// Fetch the interface fault this refers to.
+    pub fn fault(&self) -> Result<Ws2InterfaceFault<'a, 'input>> {
+        let reference = required_attribute(self.0, "ref")?;
+        let name = resolve_qualified(self.0, reference, WsComponentKind::Fault)
+            .map_err(|e| WsError::new(self.0, e))?;
+        let interface = Ws2Interface(
+            self.0
+                .parent()
+                .and_then(|n| n.parent())
+                .ok_or_else(|| WsError::new(self.0, WsErrorType::NoParentNode))?,
+        );
+
+        for interface in interface.ancestry()? {
+            if interface.target_namespace()? != name.namespace().unwrap_or_default() {
+                continue;
+            }
+
+            if let Some(fault) = interface
+                .faults()
+                .find(|f| f.name().ok() == Some(name.name()))
+            {
+                return Ok(fault);
+            }
+        }
+
+        Err(WsError::new(
+            self.0,
+            WsErrorType::UnknownComponent {
+                name: name.into(),
+                kind: WsComponentKind::Fault,
+            },
+        ))
+    }
+
+    /// Determine whether the fault is sent by the service, rather than received.
+    pub fn is_output(&self) -> bool {
+        self.0.has_tag_name((WSDL2_NS, "outfault"))
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// A named fault of a [Ws2Interface], which its operations may refer to.
+#[derive(Debug, Clone)]
+pub struct Ws2InterfaceFault<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> Ws2InterfaceFault<'a, 'input> {
+    pub fn name(&self) -> Result<&'a str> {
+        required_attribute(self.0, "name")
+    }
+
+    /// Retrieve the content model of the fault: the qualified name of a
+    /// schema element, or one of the tokens `#any`, `#none` and `#other`.
+    pub fn element(&self) -> Option<&'a str> {
+        self.0.attribute("element")
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// An operation of a [Ws2Interface].
+#[derive(Debug, Clone)]
+pub struct Ws2InterfaceOperation<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> Ws2InterfaceOperation<'a, 'input> {
+    pub fn name(&self) -> Result<&'a str> {
+        required_attribute(self.0, "name")
+    }
+
+    /// Retrieve the URI of the message exchange pattern. This defaults to in-out.
+    pub fn pattern_uri(&self) -> &'a str {
+        self.0
+            .attribute("pattern")
+            .unwrap_or("http://www.w3.org/ns/wsdl/in-out")
+    }
+
+    /// Retrieve the message exchange pattern of the operation.
+    pub fn pattern(&self) -> Result<MessageExchangePattern> {
+        MessageExchangePattern::from_uri(self.pattern_uri())
+            .ok_or_else(|| invalid_attribute(self.0, "pattern"))
+    }
+
+    /// Retrieve the URIs of the styles the operation follows. If unspecified,
+    /// this is the `styleDefault` of the interface.
+    pub fn style(&self) -> Vec<&'a str> {
+        let style = self.0.attribute("style").or_else(|| {
+            self.0
+                .parent()
+                .and_then(|interface| interface.attribute("styleDefault"))
+        });
+
+        style
+            .map(|s| s.split_whitespace().collect())
+            .unwrap_or_default()
+    }
+
+    /// Retrieve the `input` and `output` messages of the operation, in order.
+    pub fn messages(&self) -> impl Iterator<Item = Ws2MessageReference<'a, 'input>> {
+        self.0
+            .children()
+            .filter(|n| n.has_tag_name((WSDL2_NS, "input")) || n.has_tag_name((WSDL2_NS, "output")))
+            .map(Ws2MessageReference)
+    }
+
+    /// Retrieve the messages received by the service.
+    pub fn inputs(&self) -> impl Iterator<Item = Ws2MessageReference<'a, 'input>> {
+        wsdl2_children(self.0, "input").map(Ws2MessageReference)
+    }
+
+    /// Retrieve the messages sent by the service.
+    pub fn outputs(&self) -> impl Iterator<Item = Ws2MessageReference<'a, 'input>> {
+        wsdl2_children(self.0, "output").map(Ws2MessageReference)
+    }
+
+    /// Retrieve the `infault` and `outfault` references of the operation.
+    pub fn faults(&self) -> impl Iterator<Item = Ws2FaultReference<'a, 'input>> {
+        self.0
+            .children()
+            .filter(|n| {
+                n.has_tag_name((WSDL2_NS, "infault")) || n.has_tag_name((WSDL2_NS, "outfault"))
+            })
+            .map(Ws2FaultReference)
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// A WSDL 2.0 `interface`, the counterpart of a WSDL 1.1 port type.
+/// Interfaces may extend others, inheriting their operations and faults.
+#[derive(Debug, Clone)]
+pub struct Ws2Interface<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> Ws2Interface<'a, 'input> {
+    pub fn name(&self) -> Result<&'a str> {
+        required_attribute(self.0, "name")
+    }
+
+    fn target_namespace(&self) -> Result<&'a str> {
+        Ws2Description::find_parent(self.0)?.target_namespace()
+    }
+
+    /// Fetch the interfaces this interface directly extends.
+    pub fn extends(&self) -> Result<Vec<Ws2Interface<'a, 'input>>> {
+        self.0
+            .attribute("extends")
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(|name| lookup(self.0, name, WsComponentKind::Interface).map(Ws2Interface))
+            .collect()
+    }
+
+    // Retrieve this interface followed by every interface it extends, directly
+    // or not, visiting each once even if the inheritance graph has cycles.
+    fn ancestry(&self) -> Result<Vec<Ws2Interface<'a, 'input>>> {
+        let mut ancestry = vec![self.clone()];
+        let mut next = 0;
+
+        while let Some(interface) = ancestry.get(next) {
+            for parent in interface.extends()? {
+                if !ancestry.iter().any(|i| i.0 == parent.0) {
+                    ancestry.push(parent);
+                }
+            }
+
+            next += 1;
+        }
+
+        Ok(ancestry)
+    }
+
+    /// Retrieve the operations declared by this interface, excluding inherited ones.
+    pub fn operations(&self) -> impl Iterator<Item = Ws2InterfaceOperation<'a, 'input>> {
+        wsdl2_children(self.0, "operation").map(Ws2InterfaceOperation)
+    }
+
+    /// Retrieve the operations of this interface, including those inherited
+    /// from the interfaces it extends.
+    pub fn all_operations(&self) -> Result<Vec<Ws2InterfaceOperation<'a, 'input>>> {
+        Ok(self
+            .ancestry()?
+            .iter()
+            .flat_map(|i| i.operations())
+            .collect())
+    }
+
+    /// Retrieve the faults declared by this interface, excluding inherited ones.
+    pub fn faults(&self) -> impl Iterator<Item = Ws2InterfaceFault<'a, 'input>> {
+        wsdl2_children(self.0, "fault").map(Ws2InterfaceFault)
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// An operation of a [Ws2Binding], which specifies the concrete format of
+/// an interface operation.
+#[derive(Debug, Clone)]
+pub struct Ws2BindingOperation<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> Ws2BindingOperation<'a, 'input> {
+    /// Fetch the interface operation this binds.
+    pub fn interface_operation(&self) -> Result<Ws2InterfaceOperation<'a, 'input>> {
+        let reference = required_attribute(self.0, "ref")?;
+        let name = resolve_qualified(self.0, reference, WsComponentKind::Operation)
+            .map_err(|e| WsError::new(self.0, e))?;
+        let binding = Ws2Binding(
+            self.0
+                .parent()
+                .ok_or_else(|| WsError::new(self.0, WsErrorType::NoParentNode))?,
+        );
+
+        if let Some(interface) = binding.interface()? {
+            for interface in interface.ancestry()? {
+                if interface.target_namespace()? != name.namespace().unwrap_or_default() {
+                    continue;
+                }
+
+                if let Some(operation) = interface
+                    .operations()
+                    .find(|o| o.name().ok() == Some(name.name()))
+                {
+                    return Ok(operation);
+                }
+            }
+        }
+
+        Err(WsError::new(
+            self.0,
+            WsErrorType::UnknownComponent {
+                name: name.into(),
+                kind: WsComponentKind::Operation,
+            },
+        ))
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// A WSDL 2.0 `binding`, which specifies the concrete message format and
+/// protocol of an interface.
+#[derive(Debug, Clone)]
+pub struct Ws2Binding<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> Ws2Binding<'a, 'input> {
+    pub fn name(&self) -> Result<&'a str> {
+        required_attribute(self.0, "name")
+    }
+
+    /// Retrieve the URI that identifies the kind of binding, such as
+    /// `http://www.w3.org/ns/wsdl/soap`.
+    pub fn binding_type(&self) -> Result<&'a str> {
+        required_attribute(self.0, "type")
+    }
+
+    /// Retrieve the protocol of the binding, if it is a SOAP or HTTP binding.
+    pub fn protocol(&self) -> Result<Option<WsProtocol>> {
+        Ok(match self.binding_type()? {
+            WSOAP_NS => match self.0.attribute((WSOAP_NS, "version")).unwrap_or("1.2") {
+                "1.1" => Some(WsProtocol::Soap11),
+                "1.2" => Some(WsProtocol::Soap12),
+                _ => return Err(invalid_attribute(self.0, "version")),
+            },
+            WHTTP_NS => Some(WsProtocol::Http),
+            _ => None,
+        })
+    }
+
+    /// Fetch the interface this binding applies to. Bindings that specify no
+    /// operations may be reusable, and omit it.
+    pub fn interface(&self) -> Result<Option<Ws2Interface<'a, 'input>>> {
+        self.0
+            .attribute("interface")
+            .map(|name| lookup(self.0, name, WsComponentKind::Interface).map(Ws2Interface))
+            .transpose()
+    }
+
+    pub fn operations(&self) -> impl Iterator<Item = Ws2BindingOperation<'a, 'input>> {
+        wsdl2_children(self.0, "operation").map(Ws2BindingOperation)
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// An `endpoint` of a [Ws2Service], the counterpart of a WSDL 1.1 port.
+#[derive(Debug, Clone)]
+pub struct Ws2Endpoint<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> Ws2Endpoint<'a, 'input> {
+    pub fn name(&self) -> Result<&'a str> {
+        required_attribute(self.0, "name")
+    }
+
+    /// Fetch the binding this endpoint is reached with.
+    pub fn binding(&self) -> Result<Ws2Binding<'a, 'input>> {
+        let name = required_attribute(self.0, "binding")?;
+        lookup(self.0, name, WsComponentKind::Binding).map(Ws2Binding)
+    }
+
+    /// Retrieve the URL of the endpoint, if specified.
+    pub fn address(&self) -> Option<&'a str> {
+        self.0.attribute("address")
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// A WSDL 2.0 `service`, which offers a single interface at one or more endpoints.
+#[derive(Debug, Clone)]
+pub struct Ws2Service<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> Ws2Service<'a, 'input> {
+    pub fn name(&self) -> Result<&'a str> {
+        required_attribute(self.0, "name")
+    }
+
+    /// Fetch the interface offered by the service.
+    pub fn interface(&self) -> Result<Ws2Interface<'a, 'input>> {
+        let name = required_attribute(self.0, "interface")?;
+        lookup(self.0, name, WsComponentKind::Interface).map(Ws2Interface)
+    }
+
+    pub fn endpoints(&self) -> impl Iterator<Item = Ws2Endpoint<'a, 'input>> {
+        wsdl2_children(self.0, "endpoint").map(Ws2Endpoint)
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// The root `description` element of a WSDL 2.0 document.
+#[derive(Debug, Clone)]
+pub struct Ws2Description<'a, 'input>(Node<'a, 'input>);
+
+impl<'a, 'input> Ws2Description<'a, 'input> {
+    /// Find the description from one of the node's parents
+    fn find_parent(node: Node<'a, 'input>) -> Result<Self> {
+        node.ancestors()
+            .skip(1)
+            .find_map(|n| Self::from_node(n).ok())
+            .ok_or_else(|| WsError::new(node, WsErrorType::NoParentNode))
+    }
+
+    pub fn from_node(node: Node<'a, 'input>) -> Result<Self> {
+        if node.has_tag_name((WSDL2_NS, "description")) {
+            Ok(Self(node))
+        } else {
+            Err(missing_root(node, "description"))
+        }
+    }
+
+    pub fn from_document(document: &'a Document<'input>) -> Result<Self> {
+        Self::from_node(document.root_element())
+    }
+
+    /// Retrieve the namespace of the components defined by this description.
+    pub fn target_namespace(&self) -> Result<&'a str> {
+        required_attribute(self.0, "targetNamespace")
+    }
+
+    /// Retrieve the schemas of the description, if it has a `types` element.
+    pub fn types(&self) -> Option<WsTypes<'a, 'input>> {
+        wsdl2_children(self.0, "types").next().map(WsTypes)
+    }
+
+    pub fn interfaces(&self) -> impl Iterator<Item = Ws2Interface<'a, 'input>> {
+        wsdl2_children(self.0, "interface").map(Ws2Interface)
+    }
+
+    pub fn bindings(&self) -> impl Iterator<Item = Ws2Binding<'a, 'input>> {
+        wsdl2_children(self.0, "binding").map(Ws2Binding)
+    }
+
+    pub fn services(&self) -> impl Iterator<Item = Ws2Service<'a, 'input>> {
+        wsdl2_children(self.0, "service").map(Ws2Service)
+    }
+
+    /// Retrieve the text of the `wsdl:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Retrieve the extensibility elements, which are the child elements from
+    /// namespaces other than WSDL's own.
+    pub fn extensions(&self) -> impl Iterator<Item = WsExtension<'a, 'input>> {
+        extension_elements(self.0).map(WsExtension)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESERVATION: &str = include_str!("../assets/greath/reservation.wsdl");
+
+    #[test]
+    fn traverse_reservation_service() {
+        let document = Document::parse(RESERVATION).unwrap();
+        let description = Ws2Description::from_document(&document).unwrap();
+        assert_eq!(
+            description.target_namespace().unwrap(),
+            "http://greath.example.com/2004/wsdl/resSvc"
+        );
+        assert_eq!(
+            description.documentation().as_deref(),
+            Some("This document describes the GreatH Web service.")
+        );
+        assert!(description.types().is_some());
+
+        let interfaces: Vec<_> = description.interfaces().collect();
+        assert_eq!(interfaces.len(), 2);
+        let reservation = &interfaces[1];
+        assert_eq!(reservation.name().unwrap(), "reservationInterface");
+        let extends = reservation.extends().unwrap();
+        assert_eq!(extends.len(), 1);
+        assert_eq!(extends[0].name().unwrap(), "reservationBaseInterface");
+        assert_eq!(reservation.faults().count(), 0);
+        assert_eq!(interfaces[0].faults().count(), 1);
+
+        let operation = reservation.operations().next().unwrap();
+        assert_eq!(operation.name().unwrap(), "opCheckAvailability");
+        assert_eq!(operation.pattern().unwrap(), MessageExchangePattern::InOut);
+        assert_eq!(operation.style(), ["http://www.w3.org/ns/wsdl/style/iri"]);
+        assert_eq!(operation.extensions().count(), 0);
+
+        let messages: Vec<_> = operation.messages().collect();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].message_label(), Some("In"));
+        assert_eq!(messages[0].element(), Some("ghns:checkAvailability"));
+        assert!(!messages[0].is_output());
+        assert_eq!(messages[1].message_label(), Some("Out"));
+        assert!(messages[1].is_output());
+        assert_eq!(operation.inputs().count(), 1);
+        assert_eq!(operation.outputs().count(), 1);
+
+        // The fault is declared by the interface that is extended.
+        let fault = operation.faults().next().unwrap();
+        assert!(fault.is_output());
+        assert_eq!(fault.message_label(), Some("Out"));
+        assert_eq!(fault.documentation(), None);
+        assert_eq!(fault.extensions().count(), 0);
+        let fault = fault.fault().unwrap();
+        assert_eq!(fault.name().unwrap(), "invalidDataFault");
+        assert_eq!(fault.element(), Some("ghns:invalidDataError"));
+
+        let binding = description.bindings().next().unwrap();
+        assert_eq!(binding.name().unwrap(), "reservationSOAPBinding");
+        assert_eq!(binding.binding_type().unwrap(), WSOAP_NS);
+        assert_eq!(binding.protocol().unwrap(), Some(WsProtocol::Soap12));
+        let interface = binding.interface().unwrap().unwrap();
+        assert_eq!(interface.node(), reservation.node());
+        let operation = binding.operations().next().unwrap();
+        let bound = operation.interface_operation().unwrap();
+        assert_eq!(bound.name().unwrap(), "opCheckAvailability");
+        let extensions: Vec<_> = operation.extensions().collect();
+        assert!(extensions.is_empty());
+
+        let service = description.services().next().unwrap();
+        assert_eq!(service.name().unwrap(), "reservationService");
+        assert_eq!(service.interface().unwrap().node(), reservation.node());
+        let endpoint = service.endpoints().next().unwrap();
+        assert_eq!(endpoint.name().unwrap(), "reservationEndpoint");
+        assert_eq!(
+            endpoint.address(),
+            Some("http://greath.example.com/2004/reservation")
+        );
+        assert_eq!(endpoint.binding().unwrap().node(), binding.node());
+    }
+
+    #[test]
+    fn inherit_operations_through_cycles() {
+        let text = r#"<description xmlns="http://www.w3.org/ns/wsdl"
+                xmlns:tns="urn:a" targetNamespace="urn:a">
+              <interface name="A" extends="tns:B" styleDefault="urn:style">
+                <operation name="First" pattern="http://www.w3.org/ns/wsdl/out-only"/>
+              </interface>
+              <interface name="B" extends="tns:A">
+                <operation name="Second" pattern="urn:unknown"/>
+              </interface>
+              <interface name="C" extends="tns:Missing"/>
+            </description>"#;
+        let document = Document::parse(text).unwrap();
+        let description = Ws2Description::from_document(&document).unwrap();
+        let interfaces: Vec<_> = description.interfaces().collect();
+
+        let names: Vec<_> = interfaces[0]
+            .all_operations()
+            .unwrap()
+            .iter()
+            .map(|o| o.name().unwrap())
+            .collect();
+        assert_eq!(names, ["First", "Second"]);
+
+        let first = interfaces[0].operations().next().unwrap();
+        assert_eq!(first.pattern().unwrap(), MessageExchangePattern::OutOnly);
+        assert_eq!(first.style(), ["urn:style"]);
+
+        let second = interfaces[1].operations().next().unwrap();
+        assert_eq!(second.pattern_uri(), "urn:unknown");
+        assert!(second.pattern().is_err());
+        assert!(second.style().is_empty());
+
+        assert!(interfaces[2].extends().is_err());
+    }
+
+    #[test]
+    fn message_exchange_patterns() {
+        for pattern in MessageExchangePattern::ALL {
+            assert_eq!(
+                MessageExchangePattern::from_uri(pattern.uri()),
+                Some(pattern)
+            );
+        }
+        assert_eq!(MessageExchangePattern::from_uri("urn:unknown"), None);
+
+        assert_eq!(
+            MessageExchangePattern::from(crate::OperationKind::OneWay),
+            MessageExchangePattern::InOnly
+        );
+        assert_eq!(
+            MessageExchangePattern::from(crate::OperationKind::RequestResponse),
+            MessageExchangePattern::InOut
+        );
+        assert_eq!(
+            MessageExchangePattern::from(crate::OperationKind::SolicitResponse),
+            MessageExchangePattern::OutIn
+        );
+        assert_eq!(
+            MessageExchangePattern::from(crate::OperationKind::Notification),
+            MessageExchangePattern::OutOnly
+        );
+    }
+}
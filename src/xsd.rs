@@ -0,0 +1,2469 @@
+//! Typed views of XML Schema components.
+//!
+//! WSDL describes the contents of messages with XML Schema, usually embedded
+//! in the `types` of the definitions, or kept in standalone schema documents.
+//! [Schema] wraps an `xs:schema` element and lists its top-level components,
+//! and [Schemas] gathers the components of several schemas by expanded name,
+//! so references between them can be followed.
+//!
+//! The child elements a [ComplexType] allows are described by its
+//! [Particle]: element declarations and wildcards, combined by nested
+//! [ModelGroup]s, each with its own [Occurs] bounds. Types derived from
+//! others by extension or restriction expose their [Derivation], from which
+//! [Type::base_chain] walks up to a built-in type, and
+//! [ComplexType::effective_particles] gathers the inherited content.
+//!
+//! Like the `Ws*` wrappers, these borrow the parsed document.
+
+use std::collections::HashMap;
+
+use roxmltree::{Document, ExpandedName, Node};
+
+use crate::wsdl::{
+    invalid_attribute, required_attribute, resolve_qualified, Result, Scope, WsComponentKind,
+    WsErrorMalformedType, WsErrorType,
+};
+use crate::{WsDefinitions, WsDiagnostic, WsError, WsSeverity, WsdlSet};
+
+/// The namespace of XML Schema.
+pub const XSD_NS: &str = "http://www.w3.org/2001/XMLSchema";
+
+fn is_xsd(node: &Node, tag: &str) -> bool {
+    node.has_tag_name((XSD_NS, tag))
+}
+
+fn xsd_children<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    tag: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children().filter(move |n| is_xsd(n, tag))
+}
+
+// Retrieve the text of a component's `xs:documentation`, with whitespace collapsed.
+fn documentation(node: Node) -> Option<String> {
+    let text = xsd_children(node, "annotation")
+        .flat_map(|n| xsd_children(n, "documentation"))
+        .flat_map(|n| n.descendants())
+        .filter(|n| n.is_text())
+        .filter_map(|n| n.text())
+        .collect::<String>();
+
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+// Resolve an attribute holding the qualified name of another component.
+fn qualified_attribute<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    attribute: &str,
+    kind: WsComponentKind,
+) -> Result<Option<ExpandedName<'a, 'a>>> {
+    node.attribute(attribute)
+        .map(|name| resolve_qualified(node, name, kind).map_err(|e| WsError::new(node, e)))
+        .transpose()
+}
+
+fn form(node: Node, attribute: &str) -> Result<Option<Form>> {
+    match node.attribute(attribute) {
+        Some("qualified") => Ok(Some(Form::Qualified)),
+        Some("unqualified") => Ok(Some(Form::Unqualified)),
+        Some(_) => Err(invalid_attribute(node, attribute)),
+        None => Ok(None),
+    }
+}
+
+fn boolean(node: Node, attribute: &str) -> Result<bool> {
+    match node.attribute(attribute) {
+        Some("true" | "1") => Ok(true),
+        Some("false" | "0") | None => Ok(false),
+        Some(_) => Err(invalid_attribute(node, attribute)),
+    }
+}
+
+// Retrieve the occurrence bounds of a particle, which default to exactly once.
+fn occurs(node: Node) -> Result<Occurs> {
+    let min = match node.attribute("minOccurs") {
+        Some(min) => min
+            .trim()
+            .parse()
+            .map_err(|_| invalid_attribute(node, "minOccurs"))?,
+        None => 1,
+    };
+
+    let max = match node.attribute("maxOccurs").map(str::trim) {
+        Some("unbounded") => MaxOccurs::Unbounded,
+        Some(max) => MaxOccurs::Bounded(
+            max.parse()
+                .map_err(|_| invalid_attribute(node, "maxOccurs"))?,
+        ),
+        None => MaxOccurs::Bounded(1),
+    };
+
+    Ok(Occurs { min, max })
+}
+
+fn unknown_component(node: Node, name: ExpandedName, kind: WsComponentKind) -> WsError {
+    WsError::new(
+        node,
+        WsErrorType::UnknownComponent {
+            name: name.into(),
+            kind,
+        },
+    )
+}
+
+fn expanded_name<'a>(namespace: Option<&'a str>, name: &'a str) -> ExpandedName<'a, 'a> {
+    match namespace {
+        Some(namespace) => (namespace, name).into(),
+        None => name.into(),
+    }
+}
+
+// Retrieve the schema enclosing a component.
+fn enclosing_schema<'a, 'input>(node: Node<'a, 'input>) -> Option<Schema<'a, 'input>> {
+    node.ancestors().find(|n| is_xsd(n, "schema")).map(Schema)
+}
+
+// Retrieve the expanded name of a component that is named in the target
+// namespace of its schema, such as a type definition.
+fn global_name<'a>(node: Node<'a, '_>) -> Result<ExpandedName<'a, 'a>> {
+    let namespace = enclosing_schema(node).and_then(|s| s.target_namespace());
+    Ok(expanded_name(namespace, required_attribute(node, "name")?))
+}
+
+// Retrieve the expanded name of an element or attribute declaration. Global
+// declarations are in the target namespace, while local ones only are if
+// they are qualified, explicitly or through the schema's default form.
+fn declaration_name<'a>(node: Node<'a, '_>, default: &str) -> Result<ExpandedName<'a, 'a>> {
+    let name = required_attribute(node, "name")?;
+    let Some(schema) = enclosing_schema(node) else {
+        return Ok(name.into());
+    };
+
+    let qualified = if node.parent() == Some(schema.0) {
+        true
+    } else {
+        match form(node, "form")? {
+            Some(f) => f == Form::Qualified,
+            None => form(schema.0, default)? == Some(Form::Qualified),
+        }
+    };
+
+    if qualified {
+        Ok(expanded_name(schema.target_namespace(), name))
+    } else {
+        Ok(name.into())
+    }
+}
+
+/// Whether local element or attribute declarations are qualified by the
+/// target namespace of their schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Form {
+    Qualified,
+    Unqualified,
+}
+
+/// A type built into XML Schema, such as `xs:string` or `xs:int`. These are
+/// referred to by name in the XML Schema namespace, but not defined by any
+/// schema document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuiltinType {
+    AnyType,
+    AnySimpleType,
+    String,
+    NormalizedString,
+    Token,
+    Language,
+    Name,
+    NcName,
+    Id,
+    IdRef,
+    IdRefs,
+    Entity,
+    Entities,
+    NmToken,
+    NmTokens,
+    QName,
+    Notation,
+    AnyUri,
+    Boolean,
+    Decimal,
+    Integer,
+    NonPositiveInteger,
+    NegativeInteger,
+    Long,
+    Int,
+    Short,
+    Byte,
+    NonNegativeInteger,
+    UnsignedLong,
+    UnsignedInt,
+    UnsignedShort,
+    UnsignedByte,
+    PositiveInteger,
+    Float,
+    Double,
+    Duration,
+    DateTime,
+    Time,
+    Date,
+    GYearMonth,
+    GYear,
+    GMonthDay,
+    GDay,
+    GMonth,
+    HexBinary,
+    Base64Binary,
+}
+
+impl BuiltinType {
+    /// Every built-in type, starting with the ur-types.
+    pub const ALL: [Self; 46] = [
+        Self::AnyType,
+        Self::AnySimpleType,
+        Self::String,
+        Self::NormalizedString,
+        Self::Token,
+        Self::Language,
+        Self::Name,
+        Self::NcName,
+        Self::Id,
+        Self::IdRef,
+        Self::IdRefs,
+        Self::Entity,
+        Self::Entities,
+        Self::NmToken,
+        Self::NmTokens,
+        Self::QName,
+        Self::Notation,
+        Self::AnyUri,
+        Self::Boolean,
+        Self::Decimal,
+        Self::Integer,
+        Self::NonPositiveInteger,
+        Self::NegativeInteger,
+        Self::Long,
+        Self::Int,
+        Self::Short,
+        Self::Byte,
+        Self::NonNegativeInteger,
+        Self::UnsignedLong,
+        Self::UnsignedInt,
+        Self::UnsignedShort,
+        Self::UnsignedByte,
+        Self::PositiveInteger,
+        Self::Float,
+        Self::Double,
+        Self::Duration,
+        Self::DateTime,
+        Self::Time,
+        Self::Date,
+        Self::GYearMonth,
+        Self::GYear,
+        Self::GMonthDay,
+        Self::GDay,
+        Self::GMonth,
+        Self::HexBinary,
+        Self::Base64Binary,
+    ];
+
+    /// Retrieve the built-in type with the given local name, if there is one.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.name() == name)
+    }
+
+    /// Retrieve the local name of the type in the XML Schema namespace.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::AnyType => "anyType",
+            Self::AnySimpleType => "anySimpleType",
+            Self::String => "string",
+            Self::NormalizedString => "normalizedString",
+            Self::Token => "token",
+            Self::Language => "language",
+            Self::Name => "Name",
+            Self::NcName => "NCName",
+            Self::Id => "ID",
+            Self::IdRef => "IDREF",
+            Self::IdRefs => "IDREFS",
+            Self::Entity => "ENTITY",
+            Self::Entities => "ENTITIES",
+            Self::NmToken => "NMTOKEN",
+            Self::NmTokens => "NMTOKENS",
+            Self::QName => "QName",
+            Self::Notation => "NOTATION",
+            Self::AnyUri => "anyURI",
+            Self::Boolean => "boolean",
+            Self::Decimal => "decimal",
+            Self::Integer => "integer",
+            Self::NonPositiveInteger => "nonPositiveInteger",
+            Self::NegativeInteger => "negativeInteger",
+            Self::Long => "long",
+            Self::Int => "int",
+            Self::Short => "short",
+            Self::Byte => "byte",
+            Self::NonNegativeInteger => "nonNegativeInteger",
+            Self::UnsignedLong => "unsignedLong",
+            Self::UnsignedInt => "unsignedInt",
+            Self::UnsignedShort => "unsignedShort",
+            Self::UnsignedByte => "unsignedByte",
+            Self::PositiveInteger => "positiveInteger",
+            Self::Float => "float",
+            Self::Double => "double",
+            Self::Duration => "duration",
+            Self::DateTime => "dateTime",
+            Self::Time => "time",
+            Self::Date => "date",
+            Self::GYearMonth => "gYearMonth",
+            Self::GYear => "gYear",
+            Self::GMonthDay => "gMonthDay",
+            Self::GDay => "gDay",
+            Self::GMonth => "gMonth",
+            Self::HexBinary => "hexBinary",
+            Self::Base64Binary => "base64Binary",
+        }
+    }
+
+    /// Retrieve the expanded name of the type.
+    pub fn expanded_name(&self) -> ExpandedName<'static, 'static> {
+        ExpandedName::from_static(XSD_NS, self.name())
+    }
+}
+
+impl std::fmt::Display for BuiltinType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xs:{}", self.name())
+    }
+}
+
+/// The upper bound of the occurrences of a particle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MaxOccurs {
+    Bounded(u32),
+    /// `maxOccurs="unbounded"`, which allows any number of occurrences.
+    Unbounded,
+}
+
+/// How many times a particle may occur, given by its `minOccurs` and
+/// `maxOccurs` attributes. Both default to 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Occurs {
+    pub min: u32,
+    pub max: MaxOccurs,
+}
+
+impl Occurs {
+    /// Whether the particle may be left out.
+    pub fn is_optional(&self) -> bool {
+        self.min == 0
+    }
+
+    /// Whether the particle may occur more than once, as the items of an array do.
+    pub fn is_repeated(&self) -> bool {
+        match self.max {
+            MaxOccurs::Bounded(max) => max > 1,
+            MaxOccurs::Unbounded => true,
+        }
+    }
+}
+
+/// How the particles of a [ModelGroup] combine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Compositor {
+    /// `xs:sequence`: every particle, in order.
+    Sequence,
+    /// `xs:choice`: exactly one of the particles.
+    Choice,
+    /// `xs:all`: every particle, in any order.
+    All,
+}
+
+/// Whether an attribute must, may or must not appear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttributeUse {
+    Optional,
+    Required,
+    Prohibited,
+}
+
+/// How the contents matched by a [Wildcard] are validated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProcessContents {
+    /// The contents must be declared and valid.
+    Strict,
+    /// The contents are validated if they are declared.
+    Lax,
+    /// The contents are not validated.
+    Skip,
+}
+
+/// How a schema refers to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchemaDirective {
+    /// `xs:import`, which brings in the components of another namespace.
+    Import,
+    /// `xs:include`, which brings in more components of the same namespace.
+    Include,
+    /// `xs:redefine`, which includes a schema and replaces some of its components.
+    Redefine,
+}
+
+impl std::fmt::Display for SchemaDirective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Import => f.write_str("import"),
+            Self::Include => f.write_str("include"),
+            Self::Redefine => f.write_str("redefine"),
+        }
+    }
+}
+
+/// How a type is derived from its base type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DerivationMethod {
+    /// The type adds particles or attributes to its base.
+    Extension,
+    /// The type narrows the values or content its base allows.
+    Restriction,
+}
+
+/// The kind of a constraining facet of a restriction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FacetKind {
+    Length,
+    MinLength,
+    MaxLength,
+    Pattern,
+    Enumeration,
+    WhiteSpace,
+    MaxInclusive,
+    MaxExclusive,
+    MinInclusive,
+    MinExclusive,
+    TotalDigits,
+    FractionDigits,
+}
+
+impl FacetKind {
+    /// Every facet kind.
+    pub const ALL: [Self; 12] = [
+        Self::Length,
+        Self::MinLength,
+        Self::MaxLength,
+        Self::Pattern,
+        Self::Enumeration,
+        Self::WhiteSpace,
+        Self::MaxInclusive,
+        Self::MaxExclusive,
+        Self::MinInclusive,
+        Self::MinExclusive,
+        Self::TotalDigits,
+        Self::FractionDigits,
+    ];
+
+    /// Retrieve the local name of the element that specifies the facet.
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Self::Length => "length",
+            Self::MinLength => "minLength",
+            Self::MaxLength => "maxLength",
+            Self::Pattern => "pattern",
+            Self::Enumeration => "enumeration",
+            Self::WhiteSpace => "whiteSpace",
+            Self::MaxInclusive => "maxInclusive",
+            Self::MaxExclusive => "maxExclusive",
+            Self::MinInclusive => "minInclusive",
+            Self::MinExclusive => "minExclusive",
+            Self::TotalDigits => "totalDigits",
+            Self::FractionDigits => "fractionDigits",
+        }
+    }
+}
+
+/// An `xs:schema`, either embedded in the `types` of a WSDL or standalone.
+#[derive(Debug, Clone)]
+pub struct Schema<'a, 'input>(pub(crate) Node<'a, 'input>);
+
+impl<'a, 'input: 'a> Schema<'a, 'input> {
+    pub fn from_node(node: Node<'a, 'input>) -> Result<Self> {
+        if is_xsd(&node, "schema") {
+            Ok(Self(node))
+        } else {
+            Err(WsError::new(
+                node,
+                WsErrorType::MalformedWsdl(WsErrorMalformedType::MissingElement(
+                    "schema".to_string(),
+                )),
+            ))
+        }
+    }
+
+    /// Wrap the root element of a standalone schema document.
+    pub fn from_document(document: &'a Document<'input>) -> Result<Self> {
+        Self::from_node(document.root_element())
+    }
+
+    /// Retrieve the namespace the components of the schema are defined in, if any.
+    pub fn target_namespace(&self) -> Option<&'a str> {
+        self.0.attribute("targetNamespace")
+    }
+
+    /// Retrieve whether local element declarations are qualified by default.
+    pub fn element_form_default(&self) -> Result<Form> {
+        Ok(form(self.0, "elementFormDefault")?.unwrap_or(Form::Unqualified))
+    }
+
+    /// Retrieve whether local attribute declarations are qualified by default.
+    pub fn attribute_form_default(&self) -> Result<Form> {
+        Ok(form(self.0, "attributeFormDefault")?.unwrap_or(Form::Unqualified))
+    }
+
+    /// Retrieve the global element declarations.
+    pub fn elements(&self) -> impl Iterator<Item = Element<'a, 'input>> {
+        xsd_children(self.0, "element").map(Element)
+    }
+
+    /// Retrieve the global attribute declarations.
+    pub fn attributes(&self) -> impl Iterator<Item = Attribute<'a, 'input>> {
+        xsd_children(self.0, "attribute").map(Attribute)
+    }
+
+    /// Retrieve the named complex type definitions.
+    pub fn complex_types(&self) -> impl Iterator<Item = ComplexType<'a, 'input>> {
+        xsd_children(self.0, "complexType").map(ComplexType)
+    }
+
+    /// Retrieve the named simple type definitions.
+    pub fn simple_types(&self) -> impl Iterator<Item = SimpleType<'a, 'input>> {
+        xsd_children(self.0, "simpleType").map(SimpleType)
+    }
+
+    /// Retrieve the named type definitions, simple or complex, in document order.
+    pub fn types(&self) -> impl Iterator<Item = Type<'a, 'input>> {
+        self.0.children().filter_map(Type::from_node)
+    }
+
+    /// Retrieve the named model group definitions.
+    pub fn groups(&self) -> impl Iterator<Item = Group<'a, 'input>> {
+        xsd_children(self.0, "group").map(Group)
+    }
+
+    /// Retrieve the named attribute group definitions.
+    pub fn attribute_groups(&self) -> impl Iterator<Item = AttributeGroup<'a, 'input>> {
+        xsd_children(self.0, "attributeGroup").map(AttributeGroup)
+    }
+
+    /// Retrieve the imports, includes and redefines of other schemas.
+    pub fn references(&self) -> impl Iterator<Item = SchemaReference<'a, 'input>> {
+        self.0.children().filter_map(SchemaReference::from_node)
+    }
+
+    /// Retrieve the text of the `xs:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// An `xs:import`, `xs:include` or `xs:redefine` of another schema.
+#[derive(Debug, Clone)]
+pub struct SchemaReference<'a, 'input>(pub(crate) Node<'a, 'input>, SchemaDirective);
+
+impl<'a, 'input: 'a> SchemaReference<'a, 'input> {
+    fn from_node(node: Node<'a, 'input>) -> Option<Self> {
+        let directive = match node.tag_name().name() {
+            _ if node.tag_name().namespace() != Some(XSD_NS) => return None,
+            "import" => SchemaDirective::Import,
+            "include" => SchemaDirective::Include,
+            "redefine" => SchemaDirective::Redefine,
+            _ => return None,
+        };
+
+        Some(Self(node, directive))
+    }
+
+    /// Retrieve how the schema is referred to.
+    pub fn directive(&self) -> SchemaDirective {
+        self.1
+    }
+
+    /// Retrieve the namespace of an imported schema. Imports without one
+    /// bring in components that have no namespace.
+    pub fn namespace(&self) -> Option<&'a str> {
+        self.0.attribute("namespace")
+    }
+
+    /// Retrieve the location of the schema. This is required by includes
+    /// and redefines, but only a hint for imports.
+    pub fn location(&self) -> Option<&'a str> {
+        self.0.attribute("schemaLocation")
+    }
+
+    // Retrieve the schema this refers to, if the set loaded it.
+    fn load(&self, set: Option<&'a WsdlSet<'input>>) -> Option<Schema<'a, 'input>> {
+        let location = self.location()?;
+        set?.referenced(self.0, location)
+            .and_then(|d| Schema::from_document(d).ok())
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// An `xs:element` declaration.
+#[derive(Debug, Clone)]
+pub struct Element<'a, 'input>(pub(crate) Node<'a, 'input>);
+
+impl<'a, 'input: 'a> Element<'a, 'input> {
+    /// Retrieve the name of the element. References to global elements have none.
+    pub fn name(&self) -> Result<&'a str> {
+        required_attribute(self.0, "name")
+    }
+
+    /// Retrieve the name of the global element this refers to, if it is a
+    /// reference rather than a declaration.
+    pub fn reference(&self) -> Result<Option<ExpandedName<'a, 'a>>> {
+        qualified_attribute(self.0, "ref", WsComponentKind::SchemaElement)
+    }
+
+    /// Retrieve the declaration of the element: the global element it
+    /// refers to, or the element itself if it is not a reference.
+    pub fn declaration(&self, schemas: &Schemas<'a, 'input>) -> Result<Element<'a, 'input>> {
+        match self.reference()? {
+            Some(name) => schemas
+                .element(name)
+                .ok_or_else(|| unknown_component(self.0, name, WsComponentKind::SchemaElement)),
+            None => Ok(self.clone()),
+        }
+    }
+
+    /// Retrieve how many times the element may occur within its model group.
+    pub fn occurs(&self) -> Result<Occurs> {
+        occurs(self.0)
+    }
+
+    /// Retrieve whether the element may be sent empty with `xsi:nil`.
+    pub fn is_nillable(&self) -> Result<bool> {
+        boolean(self.0, "nillable")
+    }
+
+    /// Retrieve the value the element takes when it is empty, if any.
+    pub fn default_value(&self) -> Option<&'a str> {
+        self.0.attribute("default")
+    }
+
+    /// Retrieve the only value the element may take, if it is fixed.
+    pub fn fixed_value(&self) -> Option<&'a str> {
+        self.0.attribute("fixed")
+    }
+
+    /// Retrieve the name of the element as it appears in instance documents.
+    pub fn expanded_name(&self) -> Result<ExpandedName<'a, 'a>> {
+        declaration_name(self.0, "elementFormDefault")
+    }
+
+    /// Retrieve the name of the element's type, if it refers to a named type.
+    pub fn type_name(&self) -> Result<Option<ExpandedName<'a, 'a>>> {
+        qualified_attribute(self.0, "type", WsComponentKind::SchemaType)
+    }
+
+    /// Retrieve the anonymous type defined within the element, if any.
+    pub fn inline_type(&self) -> Option<Type<'a, 'input>> {
+        self.0.children().find_map(Type::from_node)
+    }
+
+    /// Retrieve the text of the `xs:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// An `xs:attribute` declaration.
+#[derive(Debug, Clone)]
+pub struct Attribute<'a, 'input>(pub(crate) Node<'a, 'input>);
+
+impl<'a, 'input: 'a> Attribute<'a, 'input> {
+    /// Retrieve the name of the attribute. References to global attributes have none.
+    pub fn name(&self) -> Result<&'a str> {
+        required_attribute(self.0, "name")
+    }
+
+    /// Retrieve the name of the global attribute this refers to, if it is a
+    /// reference rather than a declaration.
+    pub fn reference(&self) -> Result<Option<ExpandedName<'a, 'a>>> {
+        qualified_attribute(self.0, "ref", WsComponentKind::SchemaAttribute)
+    }
+
+    /// Retrieve the declaration of the attribute: the global attribute it
+    /// refers to, or the attribute itself if it is not a reference.
+    pub fn declaration(&self, schemas: &Schemas<'a, 'input>) -> Result<Attribute<'a, 'input>> {
+        match self.reference()? {
+            Some(name) => schemas
+                .attribute(name)
+                .ok_or_else(|| unknown_component(self.0, name, WsComponentKind::SchemaAttribute)),
+            None => Ok(self.clone()),
+        }
+    }
+
+    /// Retrieve whether the attribute must appear. This defaults to optional.
+    pub fn usage(&self) -> Result<AttributeUse> {
+        match self.0.attribute("use") {
+            Some("optional") | None => Ok(AttributeUse::Optional),
+            Some("required") => Ok(AttributeUse::Required),
+            Some("prohibited") => Ok(AttributeUse::Prohibited),
+            Some(_) => Err(invalid_attribute(self.0, "use")),
+        }
+    }
+
+    /// Retrieve the value the attribute takes when it is absent, if any.
+    pub fn default_value(&self) -> Option<&'a str> {
+        self.0.attribute("default")
+    }
+
+    /// Retrieve the only value the attribute may take, if it is fixed.
+    pub fn fixed_value(&self) -> Option<&'a str> {
+        self.0.attribute("fixed")
+    }
+
+    /// Retrieve the name of the attribute as it appears in instance documents.
+    pub fn expanded_name(&self) -> Result<ExpandedName<'a, 'a>> {
+        declaration_name(self.0, "attributeFormDefault")
+    }
+
+    /// Retrieve the name of the attribute's type, if it refers to a named type.
+    pub fn type_name(&self) -> Result<Option<ExpandedName<'a, 'a>>> {
+        qualified_attribute(self.0, "type", WsComponentKind::SchemaType)
+    }
+
+    /// Retrieve the anonymous simple type defined within the attribute, if any.
+    pub fn inline_type(&self) -> Option<SimpleType<'a, 'input>> {
+        xsd_children(self.0, "simpleType").next().map(SimpleType)
+    }
+
+    /// Retrieve the text of the `xs:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// An `xs:complexType` definition, which describes elements with child
+/// elements or attributes.
+#[derive(Debug, Clone)]
+pub struct ComplexType<'a, 'input>(pub(crate) Node<'a, 'input>);
+
+impl<'a, 'input: 'a> ComplexType<'a, 'input> {
+    /// Retrieve the name of the type. Anonymous types have none.
+    pub fn name(&self) -> Result<&'a str> {
+        required_attribute(self.0, "name")
+    }
+
+    pub fn expanded_name(&self) -> Result<ExpandedName<'a, 'a>> {
+        global_name(self.0)
+    }
+
+    /// Retrieve the particle describing the child elements of the type, if
+    /// it declares them directly. Types without child elements have none.
+    pub fn particle(&self) -> Option<Particle<'a, 'input>> {
+        self.0.children().find_map(Particle::from_node)
+    }
+
+    /// Retrieve the attributes declared directly by the type.
+    pub fn attributes(&self) -> impl Iterator<Item = Attribute<'a, 'input>> {
+        xsd_children(self.0, "attribute").map(Attribute)
+    }
+
+    /// Retrieve the references to attribute groups made directly by the type.
+    pub fn attribute_groups(&self) -> impl Iterator<Item = AttributeGroup<'a, 'input>> {
+        xsd_children(self.0, "attributeGroup").map(AttributeGroup)
+    }
+
+    /// Retrieve whether text may appear between the child elements.
+    pub fn is_mixed(&self) -> Result<bool> {
+        boolean(self.0, "mixed")
+    }
+
+    /// Retrieve the extension or restriction the type is derived by, if any.
+    /// Types without one are implicitly restrictions of `xs:anyType`.
+    pub fn derivation(&self) -> Option<Derivation<'a, 'input>> {
+        self.0
+            .children()
+            .filter(|n| is_xsd(n, "complexContent") || is_xsd(n, "simpleContent"))
+            .flat_map(|n| n.children())
+            .find(|n| is_xsd(n, "extension") || is_xsd(n, "restriction"))
+            .map(Derivation)
+    }
+
+    /// Retrieve whether the type has text content rather than child elements.
+    pub fn has_simple_content(&self) -> bool {
+        xsd_children(self.0, "simpleContent").next().is_some()
+    }
+
+    /// Retrieve the particles describing the child elements of the type,
+    /// including those inherited from its base types. They are in the order
+    /// the elements appear, as if in a sequence: an extension appends its
+    /// particles to those of its base, while a restriction restates them.
+    pub fn effective_particles(
+        &self,
+        schemas: &Schemas<'a, 'input>,
+    ) -> Result<Vec<Particle<'a, 'input>>> {
+        let bases = Type::Complex(self.clone()).base_chain(schemas)?;
+        let types = std::iter::once(self.clone())
+            .chain(bases.into_iter().map_while(|b| match b {
+                ResolvedType::Defined(Type::Complex(t)) => Some(t),
+                _ => None,
+            }))
+            .collect::<Vec<_>>();
+
+        let mut particles = Vec::new();
+        for typ in types.iter().rev() {
+            match typ.derivation() {
+                Some(d) if d.method() == DerivationMethod::Extension => {
+                    particles.extend(d.particle())
+                }
+                Some(d) => particles = d.particle().into_iter().collect(),
+                None => particles = typ.particle().into_iter().collect(),
+            }
+        }
+
+        Ok(particles)
+    }
+
+    /// Retrieve the text of the `xs:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// An `xs:simpleType` definition, which describes text content.
+#[derive(Debug, Clone)]
+pub struct SimpleType<'a, 'input>(pub(crate) Node<'a, 'input>);
+
+impl<'a, 'input: 'a> SimpleType<'a, 'input> {
+    /// Retrieve the name of the type. Anonymous types have none.
+    pub fn name(&self) -> Result<&'a str> {
+        required_attribute(self.0, "name")
+    }
+
+    pub fn expanded_name(&self) -> Result<ExpandedName<'a, 'a>> {
+        global_name(self.0)
+    }
+
+    /// Retrieve the restriction the type is defined by. Lists and unions
+    /// have none.
+    pub fn derivation(&self) -> Option<Derivation<'a, 'input>> {
+        xsd_children(self.0, "restriction").next().map(Derivation)
+    }
+
+    /// Retrieve the values the type is restricted to. A type without
+    /// enumerations of its own has those of its nearest base that does.
+    pub fn enumerations(&self, schemas: &Schemas<'a, 'input>) -> Result<Vec<&'a str>> {
+        let bases = Type::Simple(self.clone()).base_chain(schemas)?;
+        let types = std::iter::once(self.clone()).chain(bases.into_iter().map_while(|b| match b {
+            ResolvedType::Defined(Type::Simple(t)) => Some(t),
+            _ => None,
+        }));
+
+        for typ in types {
+            let values = typ
+                .derivation()
+                .map(|d| d.enumerations().collect::<Vec<_>>())
+                .unwrap_or_default();
+
+            if !values.is_empty() {
+                return Ok(values);
+            }
+        }
+
+        Ok(Vec::new())
+    }
+
+    /// Retrieve the text of the `xs:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// A type definition, either simple or complex.
+#[derive(Debug, Clone)]
+pub enum Type<'a, 'input> {
+    Simple(SimpleType<'a, 'input>),
+    Complex(ComplexType<'a, 'input>),
+}
+
+impl<'a, 'input: 'a> Type<'a, 'input> {
+    fn from_node(node: Node<'a, 'input>) -> Option<Self> {
+        if is_xsd(&node, "simpleType") {
+            Some(Self::Simple(SimpleType(node)))
+        } else if is_xsd(&node, "complexType") {
+            Some(Self::Complex(ComplexType(node)))
+        } else {
+            None
+        }
+    }
+
+    /// Retrieve the name of the type. Anonymous types have none.
+    pub fn name(&self) -> Result<&'a str> {
+        required_attribute(self.node(), "name")
+    }
+
+    pub fn expanded_name(&self) -> Result<ExpandedName<'a, 'a>> {
+        global_name(self.node())
+    }
+
+    /// Retrieve the extension or restriction the type is derived by, if any.
+    pub fn derivation(&self) -> Option<Derivation<'a, 'input>> {
+        match self {
+            Self::Simple(t) => t.derivation(),
+            Self::Complex(t) => t.derivation(),
+        }
+    }
+
+    /// Retrieve the type this one is derived from, if any. This is either
+    /// named by the `base` attribute, or defined inline by a simple type
+    /// restriction.
+    pub fn base(&self, schemas: &Schemas<'a, 'input>) -> Result<Option<ResolvedType<'a, 'input>>> {
+        let Some(derivation) = self.derivation() else {
+            return Ok(None);
+        };
+
+        let Some(name) = derivation.base_name()? else {
+            return Ok(derivation
+                .inline_base()
+                .map(|t| ResolvedType::Defined(Type::Simple(t))));
+        };
+
+        match schemas.resolve_type(name) {
+            // A type of an `xs:redefine` derives from the definition it
+            // replaces, which has the same name.
+            Some(ResolvedType::Defined(t)) if t.node() == self.node() => Ok(schemas
+                .original(self.node())
+                .and_then(Type::from_node)
+                .map(ResolvedType::Defined)),
+            Some(base) => Ok(Some(base)),
+            None => Err(unknown_component(
+                derivation.0,
+                name,
+                WsComponentKind::SchemaType,
+            )),
+        }
+    }
+
+    /// Retrieve the types this one is derived from, starting with its base
+    /// and ending with a built-in type or a type that is not derived.
+    pub fn base_chain(
+        &self,
+        schemas: &Schemas<'a, 'input>,
+    ) -> Result<Vec<ResolvedType<'a, 'input>>> {
+        let mut chain = Vec::new();
+        let mut seen = vec![self.node()];
+        let mut current = self.clone();
+
+        while let Some(base) = current.base(schemas)? {
+            let ResolvedType::Defined(typ) = &base else {
+                chain.push(base);
+                break;
+            };
+
+            if seen.contains(&typ.node()) {
+                return Err(WsError::new(
+                    current.node(),
+                    WsErrorType::CircularDefinition {
+                        name: typ.expanded_name()?.into(),
+                        kind: WsComponentKind::SchemaType,
+                    },
+                ));
+            }
+
+            seen.push(typ.node());
+            current = typ.clone();
+            chain.push(base);
+        }
+
+        Ok(chain)
+    }
+
+    /// Retrieve the text of the `xs:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.node())
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        match self {
+            Self::Simple(t) => t.node(),
+            Self::Complex(t) => t.node(),
+        }
+    }
+}
+
+/// A type referred to by name, which is either defined by a schema or built
+/// into XML Schema.
+#[derive(Debug, Clone)]
+pub enum ResolvedType<'a, 'input> {
+    Defined(Type<'a, 'input>),
+    Builtin(BuiltinType),
+}
+
+/// An `xs:group` definition, which names a model group for reuse.
+#[derive(Debug, Clone)]
+pub struct Group<'a, 'input>(pub(crate) Node<'a, 'input>);
+
+impl<'a, 'input: 'a> Group<'a, 'input> {
+    /// Retrieve the name of the group. References to groups have none.
+    pub fn name(&self) -> Result<&'a str> {
+        required_attribute(self.0, "name")
+    }
+
+    pub fn expanded_name(&self) -> Result<ExpandedName<'a, 'a>> {
+        global_name(self.0)
+    }
+
+    /// Retrieve the name of the group this refers to, if it is a reference
+    /// rather than a definition.
+    pub fn reference(&self) -> Result<Option<ExpandedName<'a, 'a>>> {
+        qualified_attribute(self.0, "ref", WsComponentKind::SchemaGroup)
+    }
+
+    /// Retrieve the definition of the group: the group it refers to, or the
+    /// group itself if it is not a reference.
+    pub fn definition(&self, schemas: &Schemas<'a, 'input>) -> Result<Group<'a, 'input>> {
+        match self.reference()? {
+            Some(name) => schemas
+                .group(name)
+                .ok_or_else(|| unknown_component(self.0, name, WsComponentKind::SchemaGroup)),
+            None => Ok(self.clone()),
+        }
+    }
+
+    /// Retrieve how many times a reference to the group may occur within
+    /// its model group.
+    pub fn occurs(&self) -> Result<Occurs> {
+        occurs(self.0)
+    }
+
+    /// Retrieve the model group the definition names. References have none;
+    /// see [Self::definition].
+    pub fn model_group(&self) -> Option<ModelGroup<'a, 'input>> {
+        self.0.children().find_map(ModelGroup::from_node)
+    }
+
+    /// Retrieve the text of the `xs:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// An `xs:attributeGroup` definition, which names a set of attributes for reuse.
+#[derive(Debug, Clone)]
+pub struct AttributeGroup<'a, 'input>(pub(crate) Node<'a, 'input>);
+
+impl<'a, 'input: 'a> AttributeGroup<'a, 'input> {
+    /// Retrieve the name of the group. References to groups have none.
+    pub fn name(&self) -> Result<&'a str> {
+        required_attribute(self.0, "name")
+    }
+
+    pub fn expanded_name(&self) -> Result<ExpandedName<'a, 'a>> {
+        global_name(self.0)
+    }
+
+    /// Retrieve the name of the group this refers to, if it is a reference
+    /// rather than a definition.
+    pub fn reference(&self) -> Result<Option<ExpandedName<'a, 'a>>> {
+        qualified_attribute(self.0, "ref", WsComponentKind::SchemaAttributeGroup)
+    }
+
+    /// Retrieve the definition of the group: the group it refers to, or the
+    /// group itself if it is not a reference.
+    pub fn definition(&self, schemas: &Schemas<'a, 'input>) -> Result<AttributeGroup<'a, 'input>> {
+        match self.reference()? {
+            Some(name) => schemas.attribute_group(name).ok_or_else(|| {
+                unknown_component(self.0, name, WsComponentKind::SchemaAttributeGroup)
+            }),
+            None => Ok(self.clone()),
+        }
+    }
+
+    /// Retrieve the attributes declared directly by the definition.
+    pub fn attributes(&self) -> impl Iterator<Item = Attribute<'a, 'input>> {
+        xsd_children(self.0, "attribute").map(Attribute)
+    }
+
+    /// Retrieve the references to other attribute groups made by the definition.
+    pub fn attribute_groups(&self) -> impl Iterator<Item = AttributeGroup<'a, 'input>> {
+        xsd_children(self.0, "attributeGroup").map(AttributeGroup)
+    }
+
+    /// Retrieve the text of the `xs:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// An `xs:sequence`, `xs:choice` or `xs:all` model group, which combines
+/// the particles within according to its [Compositor].
+#[derive(Debug, Clone)]
+pub struct ModelGroup<'a, 'input>(pub(crate) Node<'a, 'input>);
+
+impl<'a, 'input: 'a> ModelGroup<'a, 'input> {
+    fn from_node(node: Node<'a, 'input>) -> Option<Self> {
+        if is_xsd(&node, "sequence") || is_xsd(&node, "choice") || is_xsd(&node, "all") {
+            Some(Self(node))
+        } else {
+            None
+        }
+    }
+
+    /// Retrieve how the particles of the model group combine.
+    pub fn compositor(&self) -> Compositor {
+        match self.0.tag_name().name() {
+            "choice" => Compositor::Choice,
+            "all" => Compositor::All,
+            _ => Compositor::Sequence,
+        }
+    }
+
+    /// Retrieve how many times the model group may occur.
+    pub fn occurs(&self) -> Result<Occurs> {
+        occurs(self.0)
+    }
+
+    /// Retrieve the particles combined by the model group, in document order.
+    pub fn particles(&self) -> impl Iterator<Item = Particle<'a, 'input>> {
+        self.0.children().filter_map(Particle::from_node)
+    }
+
+    /// Retrieve the text of the `xs:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// A particle of a content model: something that matches child elements,
+/// with its own occurrence bounds.
+#[derive(Debug, Clone)]
+pub enum Particle<'a, 'input> {
+    /// A local element declaration, or a reference to a global one.
+    Element(Element<'a, 'input>),
+    /// A nested model group.
+    ModelGroup(ModelGroup<'a, 'input>),
+    /// A reference to a named model group.
+    Group(Group<'a, 'input>),
+    /// An `xs:any` wildcard.
+    Any(Wildcard<'a, 'input>),
+}
+
+impl<'a, 'input: 'a> Particle<'a, 'input> {
+    fn from_node(node: Node<'a, 'input>) -> Option<Self> {
+        if is_xsd(&node, "element") {
+            Some(Self::Element(Element(node)))
+        } else if is_xsd(&node, "group") {
+            Some(Self::Group(Group(node)))
+        } else if is_xsd(&node, "any") {
+            Some(Self::Any(Wildcard(node)))
+        } else {
+            ModelGroup::from_node(node).map(Self::ModelGroup)
+        }
+    }
+
+    /// Retrieve how many times the particle may occur.
+    pub fn occurs(&self) -> Result<Occurs> {
+        occurs(self.node())
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        match self {
+            Self::Element(p) => p.node(),
+            Self::ModelGroup(p) => p.node(),
+            Self::Group(p) => p.node(),
+            Self::Any(p) => p.node(),
+        }
+    }
+}
+
+/// An `xs:any` wildcard, which matches elements from a set of namespaces
+/// whether the schema declares them or not.
+#[derive(Debug, Clone)]
+pub struct Wildcard<'a, 'input>(pub(crate) Node<'a, 'input>);
+
+impl<'a, 'input: 'a> Wildcard<'a, 'input> {
+    /// Retrieve the namespaces the wildcard matches, as written: either
+    /// `##any`, `##other`, or a list of namespaces, `##targetNamespace` and
+    /// `##local`. This defaults to `##any`.
+    pub fn namespace(&self) -> &'a str {
+        self.0.attribute("namespace").unwrap_or("##any")
+    }
+
+    /// Retrieve how the matched elements are validated. This defaults to strict.
+    pub fn process_contents(&self) -> Result<ProcessContents> {
+        match self.0.attribute("processContents") {
+            Some("strict") | None => Ok(ProcessContents::Strict),
+            Some("lax") => Ok(ProcessContents::Lax),
+            Some("skip") => Ok(ProcessContents::Skip),
+            Some(_) => Err(invalid_attribute(self.0, "processContents")),
+        }
+    }
+
+    /// Retrieve how many times the wildcard may match.
+    pub fn occurs(&self) -> Result<Occurs> {
+        occurs(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// An `xs:extension` or `xs:restriction`, which derives a type from a base type.
+#[derive(Debug, Clone)]
+pub struct Derivation<'a, 'input>(pub(crate) Node<'a, 'input>);
+
+impl<'a, 'input: 'a> Derivation<'a, 'input> {
+    /// Retrieve whether the derivation extends or restricts its base.
+    pub fn method(&self) -> DerivationMethod {
+        if is_xsd(&self.0, "extension") {
+            DerivationMethod::Extension
+        } else {
+            DerivationMethod::Restriction
+        }
+    }
+
+    /// Retrieve the name of the base type. A simple type restriction may
+    /// define its base inline instead; see [Self::inline_base].
+    pub fn base_name(&self) -> Result<Option<ExpandedName<'a, 'a>>> {
+        qualified_attribute(self.0, "base", WsComponentKind::SchemaType)
+    }
+
+    /// Retrieve the anonymous base type of a simple type restriction, if any.
+    pub fn inline_base(&self) -> Option<SimpleType<'a, 'input>> {
+        xsd_children(self.0, "simpleType").next().map(SimpleType)
+    }
+
+    /// Retrieve the particle describing the child elements added by an
+    /// extension, or allowed by a restriction.
+    pub fn particle(&self) -> Option<Particle<'a, 'input>> {
+        self.0.children().find_map(Particle::from_node)
+    }
+
+    /// Retrieve the attributes declared by the derivation.
+    pub fn attributes(&self) -> impl Iterator<Item = Attribute<'a, 'input>> {
+        xsd_children(self.0, "attribute").map(Attribute)
+    }
+
+    /// Retrieve the references to attribute groups made by the derivation.
+    pub fn attribute_groups(&self) -> impl Iterator<Item = AttributeGroup<'a, 'input>> {
+        xsd_children(self.0, "attributeGroup").map(AttributeGroup)
+    }
+
+    /// Retrieve the constraining facets of a restriction, in document order.
+    pub fn facets(&self) -> impl Iterator<Item = Facet<'a, 'input>> {
+        self.0.children().filter_map(Facet::from_node)
+    }
+
+    /// Retrieve the values of the `xs:enumeration` facets of a restriction.
+    pub fn enumerations(&self) -> impl Iterator<Item = &'a str> {
+        self.facets()
+            .filter(|f| f.kind() == FacetKind::Enumeration)
+            .filter_map(|f| f.value().ok())
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+/// A constraining facet of a restriction, such as `xs:pattern` or `xs:maxLength`.
+#[derive(Debug, Clone)]
+pub struct Facet<'a, 'input>(pub(crate) Node<'a, 'input>, FacetKind);
+
+impl<'a, 'input: 'a> Facet<'a, 'input> {
+    fn from_node(node: Node<'a, 'input>) -> Option<Self> {
+        if node.tag_name().namespace() != Some(XSD_NS) {
+            return None;
+        }
+
+        FacetKind::ALL
+            .into_iter()
+            .find(|k| k.tag() == node.tag_name().name())
+            .map(|k| Self(node, k))
+    }
+
+    /// Retrieve which constraint the facet places on values.
+    pub fn kind(&self) -> FacetKind {
+        self.1
+    }
+
+    /// Retrieve the value of the facet, as written.
+    pub fn value(&self) -> Result<&'a str> {
+        required_attribute(self.0, "value")
+    }
+
+    /// Retrieve whether types derived from this one may not change the facet.
+    pub fn is_fixed(&self) -> Result<bool> {
+        boolean(self.0, "fixed")
+    }
+
+    /// Retrieve the text of the `xs:documentation` element, if any. Whitespace
+    /// is normalized.
+    pub fn documentation(&self) -> Option<String> {
+        documentation(self.0)
+    }
+
+    /// Return the XML node this struct is associated with
+    pub fn node(&self) -> Node<'a, 'input> {
+        self.0
+    }
+}
+
+// The components of one kind, by namespace and local name. Components
+// without a namespace are filed under the empty namespace.
+type Symbols<'a, 'input> = HashMap<&'a str, HashMap<&'a str, Node<'a, 'input>>>;
+
+/// The top-level components of several schemas, by expanded name. If a name
+/// is defined more than once, the first definition added wins.
+///
+/// When gathered from a [WsdlSet], the imports, includes and redefines of the
+/// schemas are followed into the documents of the set. A schema included
+/// without a target namespace of its own, known as a chameleon include, takes
+/// on the namespace of the schema including it; its components can be found
+/// under either namespace, though they report their own.
+#[derive(Debug, Clone, Default)]
+pub struct Schemas<'a, 'input> {
+    schemas: Vec<Schema<'a, 'input>>,
+    components: HashMap<WsComponentKind, Symbols<'a, 'input>>,
+    // Every schema gathered, along with the namespace it was gathered into.
+    gathered: Vec<(Node<'a, 'input>, Option<&'a str>)>,
+    // The components of `xs:redefine`s, along with those they replace.
+    redefined: Vec<(Node<'a, 'input>, Node<'a, 'input>)>,
+    // The imports, includes and redefines that were not followed.
+    unresolved: Vec<SchemaReference<'a, 'input>>,
+}
+
+impl<'a, 'input: 'a> Schemas<'a, 'input> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gather the schemas embedded in the `types` of the given definitions.
+    /// If they were retrieved from a [WsdlSet], the schemas of every document
+    /// of the set are gathered, as by [Self::from_set].
+    pub fn from_definitions(definitions: &WsDefinitions<'a, 'input>) -> Self {
+        match definitions.1 {
+            Scope::Document => Self::from_inline([definitions.clone()], None),
+            Scope::Set(set) => Self::from_set(set),
+            Scope::Index(index) => index.schemas().clone(),
+        }
+    }
+
+    /// Gather the schemas embedded in the WSDL documents of a set, followed
+    /// by its standalone schema documents.
+    pub fn from_set(set: &'a WsdlSet<'input>) -> Self {
+        let mut schemas = Self::from_inline(set.definitions(), Some(set));
+        let (qualified, chameleons): (Vec<_>, Vec<_>) =
+            set.schemas().partition(|s| s.target_namespace().is_some());
+
+        for schema in qualified {
+            schemas.gather(schema.clone(), schema.target_namespace(), Some(set));
+        }
+
+        // Schemas without a target namespace take that of the schemas that
+        // include them, so they only stand on their own if nothing does.
+        for schema in chameleons {
+            if !schemas.gathered.iter().any(|(n, _)| *n == schema.0) {
+                schemas.gather(schema, None, Some(set));
+            }
+        }
+
+        schemas
+    }
+
+    fn from_inline(
+        definitions: impl IntoIterator<Item = WsDefinitions<'a, 'input>>,
+        set: Option<&'a WsdlSet<'input>>,
+    ) -> Self {
+        let mut schemas = Self::new();
+        for types in definitions
+            .into_iter()
+            .filter_map(|d| d.types().ok().flatten())
+        {
+            for schema in types.schemas().into_iter().flatten() {
+                schemas.gather(schema.clone(), schema.target_namespace(), set);
+            }
+        }
+
+        schemas
+    }
+
+    // Look up components in the schemas visible from a WSDL node, which may
+    // be the definitions themselves. The schemas of an index are borrowed;
+    // otherwise they are gathered again on every call.
+    pub(crate) fn in_scope<R>(
+        node: Node<'a, 'input>,
+        scope: Scope<'a, 'input>,
+        lookup: impl FnOnce(&Self) -> R,
+    ) -> Result<R> {
+        let schemas = match scope {
+            Scope::Document => {
+                let definitions =
+                    WsDefinitions::from_node(node).or_else(|_| WsDefinitions::find_parent(node))?;
+                Self::from_inline([definitions], None)
+            }
+            Scope::Set(set) => Self::from_set(set),
+            Scope::Index(index) => return Ok(lookup(index.schemas())),
+        };
+
+        Ok(lookup(&schemas))
+    }
+
+    /// Add the components of a schema. Its imports, includes and redefines
+    /// are not followed; add the schemas they refer to as well.
+    pub fn add(&mut self, schema: Schema<'a, 'input>) -> &mut Self {
+        let namespace = schema.target_namespace();
+        self.gather(schema, namespace, None);
+        self
+    }
+
+    // Add the components of a schema to the given namespace, then those of
+    // the schemas it refers to. Each schema is only gathered once into each
+    // namespace, which also ends cycles of references.
+    fn gather(
+        &mut self,
+        schema: Schema<'a, 'input>,
+        namespace: Option<&'a str>,
+        set: Option<&'a WsdlSet<'input>>,
+    ) {
+        if self.gathered.contains(&(schema.0, namespace)) {
+            return;
+        }
+
+        self.gathered.push((schema.0, namespace));
+
+        // Redefinitions are registered first, so that they take precedence
+        // over the components they replace.
+        for redefine in xsd_children(schema.0, "redefine") {
+            self.register(redefine, namespace);
+        }
+
+        self.register(schema.0, namespace);
+        if schema.target_namespace() != namespace {
+            self.register(schema.0, schema.target_namespace());
+        }
+
+        self.schemas.push(schema.clone());
+
+        for reference in schema.references() {
+            let Some(loaded) = reference.load(set) else {
+                if !self.unresolved.iter().any(|r| r.0 == reference.0) {
+                    self.unresolved.push(reference);
+                }
+                continue;
+            };
+
+            if reference.directive() == SchemaDirective::Import {
+                self.gather(loaded.clone(), loaded.target_namespace(), set);
+                continue;
+            }
+
+            self.gather(loaded.clone(), loaded.target_namespace().or(namespace), set);
+
+            for redefinition in reference
+                .0
+                .children()
+                .filter(|n| n.attribute("name").is_some())
+            {
+                if let Some(original) = Self::find_original(loaded.clone(), redefinition, set) {
+                    self.redefined.push((redefinition, original));
+                }
+            }
+        }
+    }
+
+    // Find the component an `xs:redefine` replaces, among those of the
+    // redefined schema and the schemas it includes or redefines in turn.
+    fn find_original(
+        redefined: Schema<'a, 'input>,
+        redefinition: Node<'a, 'input>,
+        set: Option<&'a WsdlSet<'input>>,
+    ) -> Option<Node<'a, 'input>> {
+        let mut pending = vec![redefined];
+        let mut visited = Vec::new();
+
+        while let Some(schema) = pending.pop() {
+            if visited.contains(&schema.0) {
+                continue;
+            }
+
+            visited.push(schema.0);
+
+            // Components the schema redefines itself take precedence over its own.
+            let original = xsd_children(schema.0, "redefine")
+                .flat_map(|r| r.children())
+                .chain(schema.0.children())
+                .find(|n| {
+                    n.tag_name() == redefinition.tag_name()
+                        && n.attribute("name") == redefinition.attribute("name")
+                });
+
+            if original.is_some() {
+                return original;
+            }
+
+            let included = schema
+                .references()
+                .filter(|r| r.directive() != SchemaDirective::Import)
+                .filter_map(|r| r.load(set))
+                .collect::<Vec<_>>();
+            pending.extend(included.into_iter().rev());
+        }
+
+        None
+    }
+
+    // Register the components defined by the children of a node.
+    fn register(&mut self, parent: Node<'a, 'input>, namespace: Option<&'a str>) {
+        for node in parent.children() {
+            let kind = match node.tag_name().name() {
+                _ if node.tag_name().namespace() != Some(XSD_NS) => continue,
+                "element" => WsComponentKind::SchemaElement,
+                "complexType" | "simpleType" => WsComponentKind::SchemaType,
+                "group" => WsComponentKind::SchemaGroup,
+                "attributeGroup" => WsComponentKind::SchemaAttributeGroup,
+                "attribute" => WsComponentKind::SchemaAttribute,
+                _ => continue,
+            };
+
+            if let Some(name) = node.attribute("name") {
+                self.components
+                    .entry(kind)
+                    .or_default()
+                    .entry(namespace.unwrap_or_default())
+                    .or_default()
+                    .entry(name)
+                    .or_insert(node);
+            }
+        }
+    }
+
+    // Retrieve the component an `xs:redefine` replaces.
+    fn original(&self, redefinition: Node<'a, 'input>) -> Option<Node<'a, 'input>> {
+        self.redefined
+            .iter()
+            .find(|(r, _)| *r == redefinition)
+            .map(|(_, original)| *original)
+    }
+
+    /// Report the references to schemas that were not loaded. Includes and
+    /// redefines that were not followed are errors. Imports are warnings,
+    /// unless another schema already provides their namespace.
+    pub fn validate(&self) -> Vec<WsDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for reference in &self.unresolved {
+            let directive = reference.directive();
+            let severity = match directive {
+                SchemaDirective::Import => {
+                    let namespace = reference.namespace();
+                    if namespace == Some(XSD_NS)
+                        || self.gathered.iter().any(|(_, n)| *n == namespace)
+                    {
+                        continue;
+                    }
+
+                    WsSeverity::Warning
+                }
+                SchemaDirective::Include | SchemaDirective::Redefine => WsSeverity::Error,
+            };
+
+            let target = reference.location().or(reference.namespace());
+            diagnostics.push(WsDiagnostic {
+                severity,
+                error: WsError::new(
+                    reference.0,
+                    WsErrorType::UnresolvedSchema {
+                        directive,
+                        reference: target.unwrap_or_default().to_string(),
+                    },
+                ),
+            });
+        }
+
+        diagnostics
+    }
+
+    /// Retrieve the schemas, in the order they were added.
+    pub fn schemas(&self) -> impl Iterator<Item = Schema<'a, 'input>> + '_ {
+        self.schemas.iter().cloned()
+    }
+
+    fn find(&self, kind: WsComponentKind, name: ExpandedName) -> Option<Node<'a, 'input>> {
+        self.components
+            .get(&kind)
+            .and_then(|s| s.get(name.namespace().unwrap_or_default()))
+            .and_then(|s| s.get(name.name()))
+            .copied()
+    }
+
+    /// Find a global element declaration by its expanded name.
+    pub fn element<'n>(
+        &self,
+        name: impl Into<ExpandedName<'n, 'n>>,
+    ) -> Option<Element<'a, 'input>> {
+        self.find(WsComponentKind::SchemaElement, name.into())
+            .map(Element)
+    }
+
+    /// Find a global attribute declaration by its expanded name.
+    pub fn attribute<'n>(
+        &self,
+        name: impl Into<ExpandedName<'n, 'n>>,
+    ) -> Option<Attribute<'a, 'input>> {
+        self.find(WsComponentKind::SchemaAttribute, name.into())
+            .map(Attribute)
+    }
+
+    /// Find a simple or complex type definition by its expanded name.
+    pub fn type_definition<'n>(
+        &self,
+        name: impl Into<ExpandedName<'n, 'n>>,
+    ) -> Option<Type<'a, 'input>> {
+        self.find(WsComponentKind::SchemaType, name.into())
+            .and_then(Type::from_node)
+    }
+
+    /// Find a type by its expanded name, whether a schema defines it or it is
+    /// built into XML Schema.
+    pub fn resolve_type<'n>(
+        &self,
+        name: impl Into<ExpandedName<'n, 'n>>,
+    ) -> Option<ResolvedType<'a, 'input>> {
+        let name = name.into();
+        if name.namespace() == Some(XSD_NS) {
+            if let Some(builtin) = BuiltinType::from_name(name.name()) {
+                return Some(ResolvedType::Builtin(builtin));
+            }
+        }
+
+        self.type_definition(name).map(ResolvedType::Defined)
+    }
+
+    /// Find a model group definition by its expanded name.
+    pub fn group<'n>(&self, name: impl Into<ExpandedName<'n, 'n>>) -> Option<Group<'a, 'input>> {
+        self.find(WsComponentKind::SchemaGroup, name.into())
+            .map(Group)
+    }
+
+    /// Find an attribute group definition by its expanded name.
+    pub fn attribute_group<'n>(
+        &self,
+        name: impl Into<ExpandedName<'n, 'n>>,
+    ) -> Option<AttributeGroup<'a, 'input>> {
+        self.find(WsComponentKind::SchemaAttributeGroup, name.into())
+            .map(AttributeGroup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MemoryResolver, WsErrorType, WsPartDefinition, WsPartKind, WsdlSources};
+
+    fn schema(target_namespace: Option<&str>, content: &str) -> String {
+        let target_namespace = target_namespace
+            .map(|ns| format!(r#" targetNamespace="{ns}" xmlns:tns="{ns}""#))
+            .unwrap_or_default();
+        format!(
+            r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"{target_namespace}>{content}</xs:schema>"#
+        )
+    }
+
+    // Load the first of the given documents, along with those it refers to.
+    fn load(documents: &[(&str, String)]) -> WsdlSources {
+        let mut resolver = MemoryResolver::new();
+        for (uri, text) in documents {
+            resolver.insert(*uri, text.as_str());
+        }
+
+        WsdlSources::load(resolver, documents[0].0).unwrap()
+    }
+
+    // The names of the elements matched by the particles, in order.
+    fn element_names<'a, 'input: 'a>(
+        particles: impl IntoIterator<Item = Particle<'a, 'input>>,
+    ) -> Vec<String> {
+        let mut names = Vec::new();
+        for particle in particles {
+            match particle {
+                Particle::Element(e) => names.push(e.name().unwrap().to_string()),
+                Particle::ModelGroup(g) => names.extend(element_names(g.particles())),
+                _ => {}
+            }
+        }
+
+        names
+    }
+
+    // Describe the diagnostics of some schemas along with the reference they name.
+    fn diagnostics(schemas: &Schemas) -> Vec<(WsSeverity, String)> {
+        schemas
+            .validate()
+            .into_iter()
+            .map(|d| match d.error.error_type() {
+                WsErrorType::UnresolvedSchema { reference, .. } => (d.severity, reference.clone()),
+                other => panic!("unexpected error {other}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn redefine_component_of_included_schema() {
+        let sources = load(&[
+            (
+                "main.xsd",
+                schema(
+                    Some("urn:a"),
+                    r#"<xs:redefine schemaLocation="base.xsd">
+                         <xs:complexType name="T">
+                           <xs:complexContent>
+                             <xs:extension base="tns:T">
+                               <xs:sequence><xs:element name="b" type="xs:int"/></xs:sequence>
+                             </xs:extension>
+                           </xs:complexContent>
+                         </xs:complexType>
+                       </xs:redefine>"#,
+                ),
+            ),
+            (
+                "base.xsd",
+                schema(Some("urn:a"), r#"<xs:include schemaLocation="parts.xsd"/>"#),
+            ),
+            (
+                "parts.xsd",
+                schema(
+                    Some("urn:a"),
+                    r#"<xs:include schemaLocation="base.xsd"/>
+                       <xs:complexType name="T">
+                         <xs:sequence><xs:element name="a" type="xs:string"/></xs:sequence>
+                       </xs:complexType>"#,
+                ),
+            ),
+        ]);
+        let set = WsdlSet::parse(&sources).unwrap();
+        let schemas = Schemas::from_set(&set);
+
+        let Some(Type::Complex(t)) = schemas.type_definition(("urn:a", "T")) else {
+            panic!("T is not a complex type");
+        };
+        assert_eq!(t.node().parent().unwrap().tag_name().name(), "redefine");
+
+        let chain = Type::Complex(t.clone()).base_chain(&schemas).unwrap();
+        assert_eq!(chain.len(), 1);
+        let (_, parts) = set
+            .documents()
+            .find(|(uri, _)| *uri == "parts.xsd")
+            .unwrap();
+        assert!(matches!(
+            &chain[0],
+            ResolvedType::Defined(base) if std::ptr::eq(base.node().document(), parts)
+        ));
+
+        let particles = t.effective_particles(&schemas).unwrap();
+        assert_eq!(element_names(particles), ["a", "b"]);
+        assert_eq!(diagnostics(&schemas), []);
+    }
+
+    #[test]
+    fn chameleon_includes_and_cycles() {
+        let sources = load(&[
+            (
+                "main.xsd",
+                schema(
+                    Some("urn:a"),
+                    r#"<xs:include schemaLocation="chameleon.xsd"/>
+                       <xs:include schemaLocation="missing.xsd"/>
+                       <xs:import schemaLocation="unqualified.xsd"/>
+                       <xs:import namespace="urn:elsewhere"/>
+                       <xs:element name="root" type="tns:Shared"/>"#,
+                ),
+            ),
+            (
+                "chameleon.xsd",
+                schema(
+                    None,
+                    r#"<xs:include schemaLocation="cycle.xsd"/>
+                       <xs:complexType name="Shared">
+                         <xs:sequence><xs:element name="leaf" type="Leaf"/></xs:sequence>
+                       </xs:complexType>"#,
+                ),
+            ),
+            (
+                "cycle.xsd",
+                schema(
+                    None,
+                    r#"<xs:include schemaLocation="chameleon.xsd"/>
+                       <xs:simpleType name="Leaf">
+                         <xs:restriction base="xs:string"/>
+                       </xs:simpleType>"#,
+                ),
+            ),
+        ]);
+        let set = WsdlSet::parse(&sources).unwrap();
+        let schemas = Schemas::from_set(&set);
+
+        // The chameleon schemas take the namespace of the including schema.
+        assert!(schemas.type_definition(("urn:a", "Shared")).is_some());
+        assert!(schemas.type_definition(("urn:a", "Leaf")).is_some());
+
+        // The chameleon schemas don't provide the components of an import
+        // without a namespace.
+        assert_eq!(
+            diagnostics(&schemas),
+            [
+                (WsSeverity::Error, "missing.xsd".to_string()),
+                (WsSeverity::Warning, "unqualified.xsd".to_string()),
+                (WsSeverity::Warning, "urn:elsewhere".to_string()),
+            ]
+        );
+    }
+
+    // Load every schema of the smgr bundle, along with the given extra documents.
+    fn smgr(extra: &[(&str, String)], skip: &str) -> WsdlSources {
+        let mut resolver = MemoryResolver::new();
+        let mut uris = Vec::new();
+        for entry in std::fs::read_dir("assets/smgr").unwrap() {
+            let path = entry.unwrap().path();
+            let uri = path.file_name().unwrap().to_str().unwrap().to_string();
+            if path.extension().is_some_and(|e| e == "xsd") && uri != skip {
+                resolver.insert(uri.as_str(), std::fs::read_to_string(&path).unwrap());
+                uris.push(uri);
+            }
+        }
+
+        for (uri, text) in extra {
+            resolver.insert(*uri, text.as_str());
+            uris.push(uri.to_string());
+        }
+
+        uris.sort();
+        let mut sources = WsdlSources::new();
+        for uri in uris {
+            sources.add_location(&resolver, &uri).unwrap();
+        }
+
+        sources
+    }
+
+    #[test]
+    fn validate_smgr_bundle() {
+        let sources = smgr(&[], "");
+        let set = WsdlSet::parse(&sources).unwrap();
+        let schemas = Schemas::from_set(&set);
+        assert_eq!(schemas.schemas().count(), 15);
+        assert_eq!(diagnostics(&schemas), []);
+
+        // Most schemas of the bundle import the user schema.
+        let sources = smgr(&[], "userimport.xsd");
+        let set = WsdlSet::parse(&sources).unwrap();
+        let diagnostics = diagnostics(&Schemas::from_set(&set));
+        assert_eq!(diagnostics.len(), 12);
+        assert!(diagnostics
+            .iter()
+            .all(|d| *d == (WsSeverity::Warning, "userimport.xsd".to_string())));
+    }
+
+    #[test]
+    fn chameleon_includes_do_not_satisfy_unqualified_imports() {
+        let sources = smgr(
+            &[
+                (
+                    "extension.xsd",
+                    schema(
+                        Some("http://xml.avaya.com/schema/import"),
+                        r#"<xs:include schemaLocation="chameleon.xsd"/>
+                           <xs:import schemaLocation="unqualified.xsd"/>"#,
+                    ),
+                ),
+                (
+                    "chameleon.xsd",
+                    schema(None, r#"<xs:simpleType name="Extra"/>"#),
+                ),
+            ],
+            "",
+        );
+        let set = WsdlSet::parse(&sources).unwrap();
+        let schemas = Schemas::from_set(&set);
+
+        assert!(schemas
+            .type_definition(("http://xml.avaya.com/schema/import", "Extra"))
+            .is_some());
+        assert_eq!(
+            diagnostics(&schemas),
+            [(WsSeverity::Warning, "unqualified.xsd".to_string())]
+        );
+    }
+
+    #[test]
+    fn inline_imports_between_schemas_of_a_document() {
+        let text = std::fs::read_to_string("assets/serversync/serversync.wsd.xml").unwrap();
+        let document = Document::parse(&text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let schemas = Schemas::from_definitions(&definitions);
+        assert_eq!(schemas.schemas().count(), 3);
+        assert_eq!(diagnostics(&schemas), []);
+
+        // The import has no location, and is only satisfied by the other schema.
+        let text = text.replace(
+            r#"targetNamespace="http://microsoft.com/wsdl/types/""#,
+            r#"targetNamespace="urn:other""#,
+        );
+        let document = Document::parse(&text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        assert_eq!(
+            diagnostics(&Schemas::from_definitions(&definitions)),
+            [(
+                WsSeverity::Warning,
+                "http://microsoft.com/wsdl/types/".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn schema_components() {
+        let text = std::fs::read_to_string("assets/serversync/serversync.wsd.xml").unwrap();
+        let document = Document::parse(&text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let schemas = definitions
+            .types()
+            .unwrap()
+            .unwrap()
+            .schemas()
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(schemas.len(), 3);
+
+        let schema = &schemas[0];
+        assert_eq!(
+            schema.target_namespace(),
+            Some("http://www.microsoft.com/SoftwareDistribution")
+        );
+        assert_eq!(schema.element_form_default().unwrap(), Form::Qualified);
+        assert_eq!(schema.attribute_form_default().unwrap(), Form::Unqualified);
+        assert_eq!(
+            schema.elements().next().unwrap().name().unwrap(),
+            "GetAuthConfig"
+        );
+
+        let references = schema.references().collect::<Vec<_>>();
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0].directive(), SchemaDirective::Import);
+        assert_eq!(
+            references[0].namespace(),
+            Some("http://microsoft.com/wsdl/types/")
+        );
+        assert_eq!(references[0].location(), None);
+
+        let text = std::fs::read_to_string("assets/smgr/userimport.xsd").unwrap();
+        let document = Document::parse(&text).unwrap();
+        let schema = Schema::from_document(&document).unwrap();
+        assert_eq!(
+            schema.target_namespace(),
+            Some("http://xml.avaya.com/schema/import")
+        );
+
+        let elements = schema
+            .elements()
+            .map(|e| e.expanded_name().unwrap().name())
+            .collect::<Vec<_>>();
+        assert_eq!(elements, ["secureStore", "user", "users"]);
+
+        let user = schema.elements().nth(1).unwrap();
+        assert_eq!(
+            user.type_name().unwrap(),
+            Some(("http://xml.avaya.com/schema/import", "xmlUser").into())
+        );
+        assert!(schema.elements().nth(2).unwrap().inline_type().is_some());
+        assert_eq!(
+            schema.types().count(),
+            schema.complex_types().count() + schema.simple_types().count()
+        );
+        assert!(Schema::from_node(definitions.node()).is_err());
+    }
+
+    #[test]
+    fn resolve_builtin_and_defined_types() {
+        for builtin in BuiltinType::ALL {
+            assert_eq!(BuiltinType::from_name(builtin.name()), Some(builtin));
+            assert_eq!(builtin.expanded_name().namespace(), Some(XSD_NS));
+        }
+        assert_eq!(BuiltinType::from_name("int"), Some(BuiltinType::Int));
+        assert_eq!(BuiltinType::from_name("integr"), None);
+
+        let schemas = Schemas::new();
+        for builtin in BuiltinType::ALL {
+            assert!(matches!(
+                schemas.resolve_type(builtin.expanded_name()),
+                Some(ResolvedType::Builtin(b)) if b == builtin
+            ));
+        }
+        assert!(schemas.resolve_type((XSD_NS, "integr")).is_none());
+        assert!(schemas.resolve_type("string").is_none());
+
+        let text = std::fs::read_to_string("assets/weather/weather.wsdl").unwrap();
+        let document = Document::parse(&text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let part = |message: &str| {
+            let message = definitions
+                .messages()
+                .unwrap()
+                .find(|m| m.name().unwrap() == message)
+                .unwrap();
+            message.parts().next().unwrap()
+        };
+
+        let zip = part("GetCityWeatherByZIPHttpGetIn");
+        assert_eq!(zip.kind().unwrap(), WsPartKind::Type);
+        assert!(matches!(
+            zip.resolve().unwrap(),
+            WsPartDefinition::Builtin(BuiltinType::String)
+        ));
+
+        let parameters = part("GetWeatherInformationSoapIn");
+        assert_eq!(parameters.kind().unwrap(), WsPartKind::Element);
+        let WsPartDefinition::Element(element) = parameters.resolve().unwrap() else {
+            panic!("parameters is not an element");
+        };
+        assert_eq!(element.name().unwrap(), "GetWeatherInformation");
+
+        let schemas = Schemas::from_definitions(&definitions);
+        let name = ("http://ws.cdyne.com/WeatherWS/", "WeatherReturn");
+        assert!(matches!(
+            schemas.type_definition(name),
+            Some(Type::Complex(_))
+        ));
+        assert!(matches!(
+            schemas.resolve_type(name),
+            Some(ResolvedType::Defined(Type::Complex(_)))
+        ));
+
+        // Unknown names in the XML Schema namespace are not built-in types.
+        let text = text.replace(r#"type="s:string""#, r#"type="s:strin""#);
+        let document = Document::parse(&text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let zip = definitions
+            .messages()
+            .unwrap()
+            .find(|m| m.name().unwrap() == "GetCityWeatherByZIPHttpGetIn")
+            .unwrap()
+            .parts()
+            .next()
+            .unwrap();
+        assert!(matches!(
+            zip.resolve().unwrap_err().error_type(),
+            WsErrorType::UnknownComponent {
+                kind: WsComponentKind::SchemaType,
+                ..
+            }
+        ));
+    }
+
+    // Retrieve the sequence a particle consists of.
+    fn sequence<'a, 'input: 'a>(particle: Option<Particle<'a, 'input>>) -> ModelGroup<'a, 'input> {
+        match particle {
+            Some(Particle::ModelGroup(group)) => {
+                assert_eq!(group.compositor(), Compositor::Sequence);
+                group
+            }
+            other => panic!("expected a sequence, found {other:?}"),
+        }
+    }
+
+    // Retrieve the particles of a model group, which must all be elements.
+    fn elements<'a, 'input: 'a>(group: &ModelGroup<'a, 'input>) -> Vec<Element<'a, 'input>> {
+        group
+            .particles()
+            .map(|p| match p {
+                Particle::Element(e) => e,
+                other => panic!("expected an element, found {other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn weather_occurrence_bounds() {
+        let text = std::fs::read_to_string("assets/weather/weather.wsdl").unwrap();
+        let document = Document::parse(&text).unwrap();
+        let definitions = WsDefinitions::from_document(&document).unwrap();
+        let schemas = Schemas::from_definitions(&definitions);
+        let namespace = "http://ws.cdyne.com/WeatherWS/";
+
+        let Some(Type::Complex(array)) =
+            schemas.type_definition((namespace, "ArrayOfWeatherDescription"))
+        else {
+            panic!("ArrayOfWeatherDescription is not a complex type");
+        };
+
+        let group = sequence(array.particle());
+        assert_eq!(
+            group.occurs().unwrap(),
+            Occurs {
+                min: 1,
+                max: MaxOccurs::Bounded(1)
+            }
+        );
+
+        let items = elements(&group);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].name().unwrap(), "WeatherDescription");
+        let occurs = items[0].occurs().unwrap();
+        assert_eq!(
+            occurs,
+            Occurs {
+                min: 0,
+                max: MaxOccurs::Unbounded
+            }
+        );
+        assert!(occurs.is_optional() && occurs.is_repeated());
+
+        // The response element wraps the array in an optional, single element.
+        let response = schemas
+            .element((namespace, "GetWeatherInformationResponse"))
+            .unwrap();
+        let Some(Type::Complex(response)) = response.inline_type() else {
+            panic!("GetWeatherInformationResponse has no inline complex type");
+        };
+        let result = elements(&sequence(response.particle())).remove(0);
+        let occurs = result.occurs().unwrap();
+        assert_eq!(
+            occurs,
+            Occurs {
+                min: 0,
+                max: MaxOccurs::Bounded(1)
+            }
+        );
+        assert!(occurs.is_optional() && !occurs.is_repeated());
+        assert_eq!(
+            result.type_name().unwrap(),
+            Some((namespace, "ArrayOfWeatherDescription").into())
+        );
+
+        let nillable = schemas
+            .element((namespace, "ArrayOfWeatherDescription"))
+            .unwrap();
+        assert!(nillable.is_nillable().unwrap());
+    }
+
+    #[test]
+    fn userimport_nested_content() {
+        let text = std::fs::read_to_string("assets/smgr/userimport.xsd").unwrap();
+        let document = Document::parse(&text).unwrap();
+        let mut schemas = Schemas::new();
+        schemas.add(Schema::from_document(&document).unwrap());
+        let namespace = "http://xml.avaya.com/schema/import";
+
+        // `users` holds an optional secure store and any number of users.
+        let users = schemas.element((namespace, "users")).unwrap();
+        let Some(Type::Complex(users)) = users.inline_type() else {
+            panic!("users has no inline complex type");
+        };
+        let children = elements(&sequence(users.particle()));
+        let bounds = children
+            .iter()
+            .map(|e| (e.name().unwrap(), e.occurs().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bounds,
+            [
+                (
+                    "secureStore",
+                    Occurs {
+                        min: 0,
+                        max: MaxOccurs::Bounded(1)
+                    }
+                ),
+                (
+                    "user",
+                    Occurs {
+                        min: 0,
+                        max: MaxOccurs::Unbounded
+                    }
+                ),
+            ]
+        );
+
+        // Each user holds an anonymous list of rule names.
+        let user = children[1].type_name().unwrap().unwrap();
+        let Some(Type::Complex(user)) = schemas.type_definition(user) else {
+            panic!("xmlUser is not a complex type");
+        };
+        let fields = elements(&sequence(user.particle()));
+        let rules = fields
+            .iter()
+            .find(|e| e.name().unwrap() == "UserProvisionRules")
+            .unwrap();
+        assert!(rules.occurs().unwrap().is_optional());
+
+        let Some(Type::Complex(rules)) = rules.inline_type() else {
+            panic!("UserProvisionRules has no inline complex type");
+        };
+        let names = elements(&sequence(rules.particle()));
+        assert_eq!(names[0].name().unwrap(), "UserProvisionRuleName");
+        assert_eq!(
+            names[0].occurs().unwrap(),
+            Occurs {
+                min: 0,
+                max: MaxOccurs::Unbounded
+            }
+        );
+        assert_eq!(
+            names[0].type_name().unwrap(),
+            Some((XSD_NS, "string").into())
+        );
+
+        // Required fields default to a single occurrence.
+        let login = fields
+            .iter()
+            .find(|e| e.name().unwrap() == "loginName")
+            .unwrap();
+        assert_eq!(
+            login.occurs().unwrap(),
+            Occurs {
+                min: 1,
+                max: MaxOccurs::Bounded(1)
+            }
+        );
+        assert!(matches!(login.inline_type(), Some(Type::Simple(_))));
+    }
+
+    #[test]
+    fn station_profile_extension_chain() {
+        // The station profile extends a type of the user schema it imports.
+        let resolver = crate::FileResolver::rooted("assets/smgr");
+        let sources = WsdlSources::load(resolver, "stationCommProfile.xsd").unwrap();
+        let set = WsdlSet::parse(&sources).unwrap();
+        let schemas = Schemas::from_set(&set);
+        assert_eq!(diagnostics(&schemas), []);
+
+        let name = (
+            "http://xml.avaya.com/schema/import_csm_cm",
+            "xmlStationProfile",
+        );
+        let Some(Type::Complex(station)) = schemas.type_definition(name) else {
+            panic!("xmlStationProfile is not a complex type");
+        };
+        assert_eq!(
+            station.derivation().unwrap().method(),
+            DerivationMethod::Extension
+        );
+
+        let chain = Type::Complex(station.clone()).base_chain(&schemas).unwrap();
+        let chain = chain
+            .iter()
+            .map(|t| match t {
+                ResolvedType::Defined(t) => t.expanded_name().unwrap(),
+                ResolvedType::Builtin(b) => b.expanded_name(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            chain,
+            [("http://xml.avaya.com/schema/import", "xmlCommProfileType").into()]
+        );
+
+        // The inherited elements come first.
+        let names = element_names(station.effective_particles(&schemas).unwrap());
+        assert_eq!(
+            names[..5],
+            [
+                "commProfileType",
+                "commProfileSubType",
+                "jobId",
+                "cmName",
+                "prefHandleId"
+            ]
+        );
+
+        // Anonymous element types list their own enumerations.
+        let mobile = station
+            .derivation()
+            .unwrap()
+            .particle()
+            .into_iter()
+            .flat_map(|p| match p {
+                Particle::ModelGroup(g) => elements(&g),
+                _ => Vec::new(),
+            })
+            .find(|e| e.name().unwrap() == "xmobileType")
+            .unwrap();
+        let Some(Type::Simple(mobile)) = mobile.inline_type() else {
+            panic!("xmobileType has no inline simple type");
+        };
+        assert_eq!(
+            mobile.enumerations(&schemas).unwrap(),
+            ["EC500", "DECT", "IPDECT", "PHS"]
+        );
+        assert!(mobile
+            .derivation()
+            .unwrap()
+            .facets()
+            .all(|f| f.kind() == FacetKind::Enumeration));
+    }
+
+    #[test]
+    fn derivation_chains() {
+        let text = schema(
+            Some("urn:a"),
+            r#"<xs:simpleType name="Color">
+                 <xs:restriction base="xs:token">
+                   <xs:enumeration value="red"/>
+                   <xs:enumeration value="green"/>
+                 </xs:restriction>
+               </xs:simpleType>
+               <xs:simpleType name="Shade">
+                 <xs:restriction base="tns:Color">
+                   <xs:maxLength value="5" fixed="true"/>
+                 </xs:restriction>
+               </xs:simpleType>
+               <xs:complexType name="Base">
+                 <xs:sequence><xs:element name="a"/><xs:element name="b"/></xs:sequence>
+               </xs:complexType>
+               <xs:complexType name="Narrow">
+                 <xs:complexContent>
+                   <xs:restriction base="tns:Base">
+                     <xs:sequence><xs:element name="a"/></xs:sequence>
+                   </xs:restriction>
+                 </xs:complexContent>
+               </xs:complexType>
+               <xs:complexType name="Wide">
+                 <xs:complexContent>
+                   <xs:extension base="tns:Narrow">
+                     <xs:sequence><xs:element name="c"/></xs:sequence>
+                   </xs:extension>
+                 </xs:complexContent>
+               </xs:complexType>
+               <xs:complexType name="Egg">
+                 <xs:complexContent><xs:extension base="tns:Chicken"/></xs:complexContent>
+               </xs:complexType>
+               <xs:complexType name="Chicken">
+                 <xs:complexContent><xs:extension base="tns:Egg"/></xs:complexContent>
+               </xs:complexType>
+               <xs:complexType name="Orphan">
+                 <xs:complexContent><xs:extension base="tns:Missing"/></xs:complexContent>
+               </xs:complexType>"#,
+        );
+        let document = Document::parse(&text).unwrap();
+        let mut schemas = Schemas::new();
+        schemas.add(Schema::from_document(&document).unwrap());
+        let find = |name| schemas.type_definition(("urn:a", name)).unwrap();
+
+        // Simple types end their chain with a built-in type, and inherit the
+        // enumerations of their base.
+        let shade = find("Shade");
+        let chain = shade.base_chain(&schemas).unwrap();
+        assert_eq!(chain.len(), 2);
+        assert!(matches!(
+            chain[1],
+            ResolvedType::Builtin(BuiltinType::Token)
+        ));
+        let Type::Simple(shade) = shade else {
+            panic!("Shade is not a simple type");
+        };
+        assert_eq!(shade.enumerations(&schemas).unwrap(), ["red", "green"]);
+        let facet = shade.derivation().unwrap().facets().next().unwrap();
+        assert_eq!(facet.kind(), FacetKind::MaxLength);
+        assert_eq!(facet.value().unwrap(), "5");
+        assert!(facet.is_fixed().unwrap());
+
+        // A restriction restates the particles of its base, and an extension
+        // appends to them.
+        let Type::Complex(wide) = find("Wide") else {
+            panic!("Wide is not a complex type");
+        };
+        assert_eq!(
+            element_names(wide.effective_particles(&schemas).unwrap()),
+            ["a", "c"]
+        );
+
+        let error = find("Egg").base_chain(&schemas).unwrap_err();
+        assert!(matches!(
+            error.error_type(),
+            WsErrorType::CircularDefinition {
+                kind: WsComponentKind::SchemaType,
+                ..
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "The type {urn:a}Egg is derived from itself"
+        );
+
+        let Type::Complex(egg) = find("Egg") else {
+            panic!("Egg is not a complex type");
+        };
+        assert!(egg.effective_particles(&schemas).is_err());
+
+        let error = find("Orphan").base_chain(&schemas).unwrap_err();
+        assert!(matches!(
+            error.error_type(),
+            WsErrorType::UnknownComponent {
+                kind: WsComponentKind::SchemaType,
+                ..
+            }
+        ));
+    }
+}